@@ -0,0 +1,136 @@
+use text_diff::file_reader::Revision;
+use text_diff::vector_comparer::CompareMode;
+
+pub const USAGE: &str = "Usage: text-diff [OPTIONS] [FIRST [SECOND]]
+       text-diff [OPTIONS] BASE LOCAL REMOTE MERGED
+       text-diff [OPTIONS] SESSION.tdiff
+
+One file or folder is filled in as the first path. Two files or folders are compared directly,
+unless --no-compare is given. Four files open the three-way merge view, which writes the result
+to MERGED, so text-diff can be used as git difftool and mergetool. A .tdiff session file opens the
+comparison that was saved in it.
+
+Options:
+  -m, --mode <MODE>        The strategy that is used to compare the files: line or token
+  -t, --theme <THEME>      The name of the theme, such as System, Light or Dark
+  -i, --ignore <REGEX>     Ignore lines that match the regular expression, can be repeated
+  -s, --session <FILE>     Open a comparison that was saved as a .tdiff session file
+      --first-rev <REV>    The git revision of the first file: a commit, a branch, HEAD or index
+      --second-rev <REV>   The git revision of the second file: a commit, a branch, HEAD or index
+      --no-compare         Only fill in the paths instead of comparing them on startup
+  -h, --help               Print this help message";
+
+#[derive(Debug, Clone, Default)]
+pub struct Arguments {
+    pub compare_mode: Option<CompareMode>,
+    pub theme: Option<String>,
+    pub ignore_patterns: Vec<String>,
+    pub no_compare: bool,
+    pub first_revision: Revision,
+    pub second_revision: Revision,
+    pub first_file: Option<String>,
+    pub second_file: Option<String>,
+    pub base_file: Option<String>,
+    pub merged_file: Option<String>,
+    pub session_file: Option<String>,
+    pub help: bool,
+}
+
+impl Arguments {
+    /// Parse the command-line arguments
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The command-line arguments, without the name of the executable
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let arguments = Arguments::parse(std::env::args().skip(1));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Arguments` or a `String` that describes why the arguments are invalid
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Arguments, String> {
+        let mut arguments = Arguments::default();
+        let mut args = args.into_iter();
+        let mut files = vec![];
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => arguments.help = true,
+                "--no-compare" => arguments.no_compare = true,
+                "-t" | "--theme" | "-i" | "--ignore" => {
+                    let value = match args.next() {
+                        Some(d) => d,
+                        None => return Err(format!("Missing value for {}", arg)),
+                    };
+
+                    if arg == "-t" || arg == "--theme" {
+                        arguments.theme = Some(value);
+                    } else {
+                        arguments.ignore_patterns.push(value);
+                    }
+                }
+                "-m" | "--mode" => {
+                    let value = match args.next() {
+                        Some(d) => d,
+                        None => return Err(format!("Missing value for {}", arg)),
+                    };
+
+                    arguments.compare_mode = match value.to_lowercase().as_str() {
+                        "line" | "lines" => Some(CompareMode::Line),
+                        "token" | "tokens" => Some(CompareMode::Token),
+                        _ => return Err(format!("Invalid compare mode: {}", value)),
+                    };
+                }
+                "-s" | "--session" => {
+                    arguments.session_file = match args.next() {
+                        Some(d) => Some(d),
+                        None => return Err(format!("Missing value for {}", arg)),
+                    };
+                }
+                "--first-rev" | "--second-rev" => {
+                    let revision = match args.next() {
+                        Some(d) => Revision::parse(&d),
+                        None => return Err(format!("Missing value for {}", arg)),
+                    };
+
+                    if arg == "--first-rev" {
+                        arguments.first_revision = revision;
+                    } else {
+                        arguments.second_revision = revision;
+                    }
+                }
+                _ if arg.starts_with('-') => return Err(format!("Unknown argument: {}", arg)),
+                _ => files.push(arg),
+            }
+        }
+
+        let mut files = files.into_iter();
+        match files.len() {
+            0 => {}
+            1 if files.as_slice()[0].ends_with(".tdiff") => arguments.session_file = files.next(),
+            1 => arguments.first_file = files.next(),
+            2 => {
+                arguments.first_file = files.next();
+                arguments.second_file = files.next();
+            }
+            4 => {
+                arguments.base_file = files.next();
+                arguments.first_file = files.next();
+                arguments.second_file = files.next();
+                arguments.merged_file = files.next();
+            }
+            n => {
+                return Err(format!(
+                    "Expected up to two files to compare or four files to merge, but got {}",
+                    n
+                ))
+            }
+        }
+
+        Ok(arguments)
+    }
+}
@@ -0,0 +1,413 @@
+/// An operation of an edit script, described by the indices in the old and the new slice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp {
+    Equal {
+        old_index: usize,
+        new_index: usize,
+        len: usize,
+    },
+    Delete {
+        old_index: usize,
+        old_len: usize,
+        new_index: usize,
+    },
+    Insert {
+        old_index: usize,
+        new_index: usize,
+        new_len: usize,
+    },
+}
+
+/// Calculate the shortest edit script that turns one slice into another
+///
+/// # Arguments
+///
+/// * `old` - The original slice
+/// * `new` - The modified slice
+///
+/// # Example
+///
+/// ```rust
+/// # use text_diff::diff::{diff, DiffOp};
+/// let ops: Vec<DiffOp> = diff(&["a", "b"], &["a", "c"]);
+/// ```
+///
+/// # Returns
+///
+/// The `Vec` of `DiffOp` values that describe how `old` should be edited to become `new`
+pub fn diff<T: PartialEq>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    let max_d = (old.len() + new.len()).div_ceil(2) + 1;
+    let mut myers = Myers {
+        old,
+        new,
+        vf: V::new(max_d),
+        vb: V::new(max_d),
+        ops: vec![],
+    };
+
+    myers.conquer(0, old.len(), 0, new.len());
+
+    let mut ops: Vec<DiffOp> = vec![];
+    for op in myers.ops {
+        // Keep deletions in front of insertions so that every change reads the same way
+        if let (
+            Some(&DiffOp::Insert {
+                old_index,
+                new_index,
+                new_len,
+            }),
+            DiffOp::Delete { old_len, .. },
+        ) = (ops.last(), op)
+        {
+            ops.pop();
+            let delete = DiffOp::Delete {
+                old_index,
+                old_len,
+                new_index,
+            };
+            match ops.last_mut() {
+                Some(DiffOp::Delete { old_len: len, .. }) => *len += old_len,
+                _ => ops.push(delete),
+            }
+            ops.push(DiffOp::Insert {
+                old_index: old_index + old_len,
+                new_index,
+                new_len,
+            });
+            continue;
+        }
+
+        match (ops.last_mut(), op) {
+            (Some(DiffOp::Equal { len, .. }), DiffOp::Equal { len: l, .. }) => *len += l,
+            (Some(DiffOp::Delete { old_len, .. }), DiffOp::Delete { old_len: l, .. }) => {
+                *old_len += l
+            }
+            (Some(DiffOp::Insert { new_len, .. }), DiffOp::Insert { new_len: l, .. }) => {
+                *new_len += l
+            }
+            _ => ops.push(op),
+        }
+    }
+
+    ops
+}
+
+/// A change between two slices: a run of deleted elements that is replaced by a run of inserted
+/// elements, either of which may be empty
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hunk {
+    pub old_index: usize,
+    pub old_len: usize,
+    pub new_index: usize,
+    pub new_len: usize,
+}
+
+/// Group the deletions and insertions of an edit script into hunks
+///
+/// # Arguments
+///
+/// * `ops` - The edit script, as returned by `diff`
+///
+/// # Example
+///
+/// ```rust
+/// # use text_diff::diff::{diff, hunks, Hunk};
+/// let hunks: Vec<Hunk> = hunks(&diff(&["a", "b"], &["a", "c"]));
+/// ```
+///
+/// # Returns
+///
+/// The `Vec` of `Hunk` values in the order in which they appear in both slices
+pub fn hunks(ops: &[DiffOp]) -> Vec<Hunk> {
+    let mut hunks: Vec<Hunk> = vec![];
+    let mut previous_equal = true;
+
+    for op in ops {
+        let hunk = match *op {
+            DiffOp::Equal { .. } => {
+                previous_equal = true;
+                continue;
+            }
+            DiffOp::Delete {
+                old_index,
+                old_len,
+                new_index,
+            } => Hunk {
+                old_index,
+                old_len,
+                new_index,
+                new_len: 0,
+            },
+            DiffOp::Insert {
+                old_index,
+                new_index,
+                new_len,
+            } => Hunk {
+                old_index,
+                old_len: 0,
+                new_index,
+                new_len,
+            },
+        };
+
+        match hunks.last_mut() {
+            Some(last) if !previous_equal => {
+                last.old_len += hunk.old_len;
+                last.new_len += hunk.new_len;
+            }
+            _ => hunks.push(hunk),
+        }
+        previous_equal = false;
+    }
+
+    hunks
+}
+
+/// A vector that can be indexed by a diagonal of the edit graph
+struct V {
+    offset: isize,
+    data: Vec<usize>,
+}
+
+impl V {
+    fn new(max_d: usize) -> V {
+        V {
+            offset: max_d as isize,
+            data: vec![0; 2 * max_d + 1],
+        }
+    }
+}
+
+impl std::ops::Index<isize> for V {
+    type Output = usize;
+
+    fn index(&self, index: isize) -> &usize {
+        &self.data[(index + self.offset) as usize]
+    }
+}
+
+impl std::ops::IndexMut<isize> for V {
+    fn index_mut(&mut self, index: isize) -> &mut usize {
+        &mut self.data[(index + self.offset) as usize]
+    }
+}
+
+/// Linear space variant of the algorithm described in "An O(ND) Difference Algorithm and Its
+/// Variations" by Eugene W. Myers
+struct Myers<'a, T> {
+    old: &'a [T],
+    new: &'a [T],
+    vf: V,
+    vb: V,
+    ops: Vec<DiffOp>,
+}
+
+impl<'a, T: PartialEq> Myers<'a, T> {
+    fn conquer(
+        &mut self,
+        mut old_lo: usize,
+        mut old_hi: usize,
+        mut new_lo: usize,
+        mut new_hi: usize,
+    ) {
+        let prefix = self.common_prefix(old_lo, old_hi, new_lo, new_hi);
+        if prefix > 0 {
+            self.ops.push(DiffOp::Equal {
+                old_index: old_lo,
+                new_index: new_lo,
+                len: prefix,
+            });
+            old_lo += prefix;
+            new_lo += prefix;
+        }
+
+        let suffix = self.common_suffix(old_lo, old_hi, new_lo, new_hi);
+        old_hi -= suffix;
+        new_hi -= suffix;
+
+        if old_lo == old_hi && new_lo < new_hi {
+            self.ops.push(DiffOp::Insert {
+                old_index: old_lo,
+                new_index: new_lo,
+                new_len: new_hi - new_lo,
+            });
+        } else if new_lo == new_hi && old_lo < old_hi {
+            self.ops.push(DiffOp::Delete {
+                old_index: old_lo,
+                old_len: old_hi - old_lo,
+                new_index: new_lo,
+            });
+        } else if old_lo < old_hi && new_lo < new_hi {
+            match self.middle_snake(old_lo, old_hi, new_lo, new_hi) {
+                Some((x, y)) if (x, y) != (old_lo, new_lo) && (x, y) != (old_hi, new_hi) => {
+                    self.conquer(old_lo, x, new_lo, y);
+                    self.conquer(x, old_hi, y, new_hi);
+                }
+                _ => {
+                    self.ops.push(DiffOp::Delete {
+                        old_index: old_lo,
+                        old_len: old_hi - old_lo,
+                        new_index: new_lo,
+                    });
+                    self.ops.push(DiffOp::Insert {
+                        old_index: old_hi,
+                        new_index: new_lo,
+                        new_len: new_hi - new_lo,
+                    });
+                }
+            }
+        }
+
+        if suffix > 0 {
+            self.ops.push(DiffOp::Equal {
+                old_index: old_hi,
+                new_index: new_hi,
+                len: suffix,
+            });
+        }
+    }
+
+    fn common_prefix(&self, old_lo: usize, old_hi: usize, new_lo: usize, new_hi: usize) -> usize {
+        self.old[old_lo..old_hi]
+            .iter()
+            .zip(self.new[new_lo..new_hi].iter())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    fn common_suffix(&self, old_lo: usize, old_hi: usize, new_lo: usize, new_hi: usize) -> usize {
+        self.old[old_lo..old_hi]
+            .iter()
+            .rev()
+            .zip(self.new[new_lo..new_hi].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Find the start of the snake in the middle of an optimal path through the edit graph
+    fn middle_snake(
+        &mut self,
+        old_lo: usize,
+        old_hi: usize,
+        new_lo: usize,
+        new_hi: usize,
+    ) -> Option<(usize, usize)> {
+        let n = old_hi - old_lo;
+        let m = new_hi - new_lo;
+        let delta = n as isize - m as isize;
+        let odd = delta & 1 == 1;
+        let max_d = ((n + m).div_ceil(2) + 1) as isize;
+
+        self.vf[1] = 0;
+        self.vb[1] = 0;
+
+        for d in 0..max_d {
+            // Search forwards from the top left corner
+            for k in (-d..=d).rev().step_by(2) {
+                let mut x = if k == -d || (k != d && self.vf[k - 1] < self.vf[k + 1]) {
+                    self.vf[k + 1]
+                } else {
+                    self.vf[k - 1] + 1
+                };
+                let y = (x as isize - k) as usize;
+                let (x0, y0) = (x, y);
+
+                if x < n && y < m {
+                    x += self.common_prefix(old_lo + x, old_hi, new_lo + y, new_hi);
+                }
+                self.vf[k] = x;
+
+                if odd && (k - delta).abs() < d && self.vf[k] + self.vb[-(k - delta)] >= n {
+                    return Some((old_lo + x0, new_lo + y0));
+                }
+            }
+
+            // Search backwards from the bottom right corner
+            for k in (-d..=d).rev().step_by(2) {
+                let mut x = if k == -d || (k != d && self.vb[k - 1] < self.vb[k + 1]) {
+                    self.vb[k + 1]
+                } else {
+                    self.vb[k - 1] + 1
+                };
+                let mut y = (x as isize - k) as usize;
+
+                if x < n && y < m {
+                    let snake = self.common_suffix(old_lo, old_hi - x, new_lo, new_hi - y);
+                    x += snake;
+                    y += snake;
+                }
+                self.vb[k] = x;
+
+                if !odd && (k - delta).abs() <= d && self.vb[k] + self.vf[-(k - delta)] >= n {
+                    return Some((old_hi - x, new_hi - y));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hunks_of(old: &str, new: &str) -> Vec<Hunk> {
+        let old: Vec<char> = old.chars().collect();
+        let new: Vec<char> = new.chars().collect();
+        hunks(&diff(&old, &new))
+    }
+
+    #[test]
+    fn equal_slices_have_no_hunks() {
+        assert_eq!(hunks_of("abc", "abc"), vec![]);
+        assert_eq!(hunks_of("", ""), vec![]);
+    }
+
+    #[test]
+    fn a_deletion_followed_by_an_insertion_is_one_hunk() {
+        assert_eq!(
+            hunks_of("abc", "axc"),
+            vec![Hunk {
+                old_index: 1,
+                old_len: 1,
+                new_index: 1,
+                new_len: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn adjacent_changes_are_one_hunk() {
+        assert_eq!(
+            hunks_of("abcd", "axyzd"),
+            vec![Hunk {
+                old_index: 1,
+                old_len: 2,
+                new_index: 1,
+                new_len: 3,
+            }]
+        );
+    }
+
+    #[test]
+    fn separate_changes_are_separate_hunks() {
+        assert_eq!(
+            hunks_of("abcde", "bcdxe"),
+            vec![
+                Hunk {
+                    old_index: 0,
+                    old_len: 1,
+                    new_index: 0,
+                    new_len: 0,
+                },
+                Hunk {
+                    old_index: 4,
+                    old_len: 0,
+                    new_index: 3,
+                    new_len: 1,
+                },
+            ]
+        );
+    }
+}
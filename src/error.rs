@@ -0,0 +1,83 @@
+use std::path::PathBuf;
+
+use crate::vector_exporter::ExportError;
+
+/// The error that occurred while reading, comparing or writing files
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A file could not be read from disk
+    Read {
+        path: String,
+        source: std::io::Error,
+    },
+    /// A file could not be written to disk
+    Write {
+        path: String,
+        source: std::io::Error,
+    },
+    /// A path does not refer to a file
+    NotAFile { path: String },
+    /// A revision could be mistaken for an option of git
+    InvalidRevision { revision: String },
+    /// A path is not valid UTF-8
+    InvalidPath { path: PathBuf },
+    /// A git command failed for a file
+    Git {
+        path: String,
+        command: String,
+        message: String,
+    },
+    /// The differences could not be exported to a file
+    Export { path: String, source: ExportError },
+    /// A file does not have the expected format
+    Parse { path: String, message: String },
+    /// A regular expression is not valid
+    InvalidPattern { pattern: String, message: String },
+    /// A native dialog could not be shown
+    Dialog {
+        operation: &'static str,
+        message: String,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Read { path, source } => write!(f, "Unable to read {}: {}", path, source),
+            Error::Write { path, source } => write!(f, "Unable to write {}: {}", path, source),
+            Error::NotAFile { path } => write!(f, "{} is not a file", path),
+            Error::InvalidRevision { revision } => {
+                write!(f, "{} is not a valid revision", revision)
+            }
+            Error::InvalidPath { path } => {
+                write!(f, "The path {} is not valid UTF-8", path.display())
+            }
+            Error::Git {
+                path,
+                command,
+                message,
+            } => write!(f, "git {} failed for {}: {}", command, path, message),
+            Error::Export { path, source } => {
+                write!(f, "Unable to export to {}: {}", path, source)
+            }
+            Error::Parse { path, message } => write!(f, "Unable to parse {}: {}", path, message),
+            Error::InvalidPattern { pattern, message } => {
+                write!(f, "Invalid pattern {}: {}", pattern, message)
+            }
+            Error::Dialog { operation, message } => {
+                write!(f, "Unable to show the {} dialog: {}", operation, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Read { source, .. } | Error::Write { source, .. } => Some(source),
+            Error::Export { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
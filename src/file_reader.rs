@@ -1,9 +1,229 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::{path::Path, process::Command};
 
-#[derive(Debug, Clone)]
+use crate::error::Error;
+
+/// The revision of a file in a local git repository
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Revision {
+    #[default]
+    WorkingTree,
+    Index,
+    Commit {
+        rev: String,
+        summary: String,
+    },
+}
+
+impl Revision {
+    /// Parse a revision as it is passed on the command-line
+    ///
+    /// # Arguments
+    ///
+    /// * `rev` - The revision, such as `HEAD`, a branch, a commit or `index` for the staged version
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::file_reader::Revision;
+    /// let revision = Revision::parse("HEAD~1");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Revision` that matches the given value
+    pub fn parse(rev: &str) -> Revision {
+        match rev {
+            "" | "worktree" => Revision::WorkingTree,
+            "index" | ":" => Revision::Index,
+            _ => Revision::Commit {
+                rev: String::from(rev),
+                summary: String::new(),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Revision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Revision::WorkingTree => write!(f, "Working tree"),
+            Revision::Index => write!(f, "Index"),
+            Revision::Commit { rev, summary } => {
+                if summary.is_empty() {
+                    write!(f, "{}", rev)
+                } else {
+                    write!(f, "{} {}", rev, summary)
+                }
+            }
+        }
+    }
+}
+
+/// A file at a specific `Revision`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSource {
+    pub path: String,
+    pub revision: Revision,
+}
+
+impl FileSource {
+    /// Initialize a new `FileSource`
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file
+    /// * `revision` - The `Revision` of the file that should be read
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::file_reader::{FileSource, Revision};
+    /// let source = FileSource::new("/path/to/file", Revision::Index);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `FileSource` instance
+    pub fn new(path: &str, revision: Revision) -> FileSource {
+        FileSource {
+            path: String::from(path),
+            revision,
+        }
+    }
+}
+
+/// The characters that end the lines of a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+}
+
+impl LineEnding {
+    /// Get the characters of the line ending
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::file_reader::LineEnding;
+    /// assert_eq!(LineEnding::CrLf.as_str(), "\r\n");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `\n` or `\r\n`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// The way the lines of a file are separated, so that the lines can be written back unchanged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineFormat {
+    pub line_ending: LineEnding,
+    pub final_newline: bool,
+}
+
+impl Default for LineFormat {
+    fn default() -> Self {
+        LineFormat {
+            line_ending: LineEnding::Lf,
+            final_newline: true,
+        }
+    }
+}
+
+impl LineFormat {
+    /// Detect the format of a text
+    ///
+    /// The line ending of the first line is used for the whole text.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text of a file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::file_reader::{LineEnding, LineFormat};
+    /// let format = LineFormat::detect("a\r\nb");
+    /// assert_eq!(format.line_ending, LineEnding::CrLf);
+    /// assert!(!format.final_newline);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `LineFormat` of the text
+    pub fn detect(text: &str) -> LineFormat {
+        let line_ending = match text.find('\n') {
+            Some(i) if text[..i].ends_with('\r') => LineEnding::CrLf,
+            _ => LineEnding::Lf,
+        };
+
+        LineFormat {
+            line_ending,
+            final_newline: text.ends_with('\n'),
+        }
+    }
+
+    /// Join lines into the text of a file with this format
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The lines of the file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::file_reader::LineFormat;
+    /// let text: String = LineFormat::default().join(&[String::from("a"), String::from("b")]);
+    /// assert_eq!(text, "a\nb\n");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The text of the file
+    pub fn join(&self, lines: &[String]) -> String {
+        let mut text = lines.join(self.line_ending.as_str());
+        if self.final_newline && !lines.is_empty() {
+            text.push_str(self.line_ending.as_str());
+        }
+        text
+    }
+
+    /// Get the size of the text that `join` creates, without creating it
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The lines of the file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::file_reader::LineFormat;
+    /// let bytes: usize = LineFormat::default().byte_len(&[String::from("a"), String::from("b")]);
+    /// assert_eq!(bytes, 4);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes of the text
+    pub fn byte_len(&self, lines: &[String]) -> usize {
+        let separators = if self.final_newline {
+            lines.len()
+        } else {
+            lines.len().saturating_sub(1)
+        };
+        lines.iter().map(String::len).sum::<usize>() + separators * self.line_ending.as_str().len()
+    }
+}
+
+/// Reads the lines of files from disk or from a local git repository
+#[derive(Debug, Clone, Default)]
 pub struct FileReader;
 
 impl FileReader {
@@ -12,6 +232,7 @@ impl FileReader {
     /// # Example
     ///
     /// ```rust
+    /// # use text_diff::file_reader::FileReader;
     /// let file_reader = FileReader::new();
     /// ```
     ///
@@ -30,31 +251,266 @@ impl FileReader {
     ///
     /// # Example
     ///
-    /// ```rust
-    /// let lines: Vec<String> = file_reader.read_lines("/path/to/file");
+    /// ```rust,no_run
+    /// # use text_diff::file_reader::FileReader;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let file_reader = FileReader::new();
+    /// let lines: Vec<String> = file_reader.read_lines("/path/to/file")?;
+    /// # Ok(())
+    /// # }
     /// ```
     ///
     /// # Returns
     ///
     /// The `Vec` that contains all the lines in the specified file or an `Error`
-    pub fn read_lines(&self, path: &str) -> Result<Vec<String>, std::io::Error> {
-        let file = File::open(path);
-        let file = match file {
-            Ok(d) => d,
-            Err(e) => return Err(e),
+    pub fn read_lines(&self, path: &str) -> Result<Vec<String>, Error> {
+        self.read_lines_with_format(path).map(|(lines, _)| lines)
+    }
+
+    /// Read the lines of a file and the way they are separated
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file that should be read
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use text_diff::file_reader::{FileReader, LineFormat};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let file_reader = FileReader::new();
+    /// let (lines, format): (Vec<String>, LineFormat) = file_reader.read_lines_with_format("/path/to/file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The lines of the file without their line endings and the `LineFormat` of the file, or an
+    /// `Error`
+    pub fn read_lines_with_format(&self, path: &str) -> Result<(Vec<String>, LineFormat), Error> {
+        match std::fs::read_to_string(path) {
+            Ok(d) => Ok(split_lines(&d)),
+            Err(e) => Err(Error::Read {
+                path: String::from(path),
+                source: e,
+            }),
+        }
+    }
+
+    /// Read the lines of a file at a specific revision
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The `FileSource` that should be read
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use text_diff::file_reader::{FileReader, FileSource, Revision};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let file_reader = FileReader::new();
+    /// let lines: Vec<String> = file_reader.read_source(&FileSource::new("/path/to/file", Revision::Index))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` that contains all the lines of the file at the specified revision or an `Error`,
+    /// also if the revision starts with `-` and would be read by git as an option
+    pub fn read_source(&self, source: &FileSource) -> Result<Vec<String>, Error> {
+        self.read_source_with_format(source).map(|(lines, _)| lines)
+    }
+
+    /// Read the lines of a file at a specific revision and the way they are separated
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The `FileSource` that should be read
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use text_diff::file_reader::{FileReader, FileSource, LineFormat, Revision};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let file_reader = FileReader::new();
+    /// let (lines, format): (Vec<String>, LineFormat) = file_reader.read_source_with_format(&FileSource::new("/path/to/file", Revision::Index))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The lines of the file at the specified revision and the `LineFormat` of the file, or an
+    /// `Error`
+    pub fn read_source_with_format(
+        &self,
+        source: &FileSource,
+    ) -> Result<(Vec<String>, LineFormat), Error> {
+        let object = match &source.revision {
+            Revision::WorkingTree => return self.read_lines_with_format(&source.path),
+            Revision::Index => String::from(":./"),
+            Revision::Commit { rev, .. } if rev.starts_with('-') => {
+                return Err(Error::InvalidRevision {
+                    revision: rev.clone(),
+                })
+            }
+            Revision::Commit { rev, .. } => format!("{}:./", rev),
         };
 
-        let reader = BufReader::new(file);
+        let path = Path::new(&source.path);
+        let file_name = match path.file_name().and_then(|f| f.to_str()) {
+            Some(d) => d,
+            None => {
+                return Err(Error::NotAFile {
+                    path: source.path.clone(),
+                })
+            }
+        };
+
+        let output = git(path, &["show", &format!("{}{}", object, file_name)])?;
 
-        let mut lines = vec![];
-        for (_index, line) in reader.lines().enumerate() {
-            let line = match line {
-                Ok(d) => d,
-                Err(e) => return Err(e),
-            };
-            lines.push(line);
+        Ok(split_lines(&output))
+    }
+
+    /// Get the revisions of a file that is tracked in a local git repository
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use text_diff::file_reader::{FileReader, Revision};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let file_reader = FileReader::new();
+    /// let revisions: Vec<Revision> = file_reader.list_revisions("/path/to/file")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` that contains the working tree, the index, `HEAD`, all local branches and the
+    /// most recent commits that touched the file, or an `Error` if the file is not in a git repository
+    pub fn list_revisions(&self, path: &str) -> Result<Vec<Revision>, Error> {
+        let path = Path::new(path);
+        let file_name = match path.file_name().and_then(|f| f.to_str()) {
+            Some(d) => d,
+            None => {
+                return Err(Error::NotAFile {
+                    path: path.display().to_string(),
+                })
+            }
+        };
+
+        let branches = git(
+            path,
+            &["for-each-ref", "--format=%(refname:short)", "refs/heads"],
+        )?;
+
+        let log = git(
+            path,
+            &["log", "-n", "25", "--format=%h %s", "--", file_name],
+        )?;
+
+        let mut revisions = vec![
+            Revision::WorkingTree,
+            Revision::Index,
+            Revision::parse("HEAD"),
+        ];
+
+        for branch in branches.lines() {
+            revisions.push(Revision::Commit {
+                rev: String::from(branch),
+                summary: String::new(),
+            });
         }
 
-        Ok(lines)
+        for commit in log.lines() {
+            let (rev, summary) = commit.split_once(' ').unwrap_or((commit, ""));
+            revisions.push(Revision::Commit {
+                rev: String::from(rev),
+                summary: String::from(summary),
+            });
+        }
+
+        Ok(revisions)
+    }
+}
+
+/// Split the text of a file into lines without their line endings
+fn split_lines(text: &str) -> (Vec<String>, LineFormat) {
+    (
+        text.lines().map(String::from).collect(),
+        LineFormat::detect(text),
+    )
+}
+
+/// Run a git command in the directory that contains the given file
+///
+/// # Arguments
+///
+/// * `path` - The path of a file in the repository
+/// * `args` - The arguments that should be passed to git
+///
+/// # Returns
+///
+/// The standard output of the command or an `Error` if the command failed
+fn git(path: &Path, args: &[&str]) -> Result<String, Error> {
+    let directory = match path.parent() {
+        Some(d) if !d.as_os_str().is_empty() => d,
+        _ => Path::new("."),
+    };
+
+    let error = |message: String| Error::Git {
+        path: path.display().to_string(),
+        command: args.join(" "),
+        message,
+    };
+
+    let output = match Command::new("git")
+        .arg("-C")
+        .arg(directory)
+        .args(args)
+        .output()
+    {
+        Ok(d) => d,
+        Err(e) => return Err(error(e.to_string())),
+    };
+
+    if !output.status.success() {
+        return Err(error(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    match String::from_utf8(output.stdout) {
+        Ok(d) => Ok(d),
+        Err(e) => Err(error(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_are_written_back_unchanged() {
+        for text in [
+            "a\nb\n",
+            "a\r\nb\r\n",
+            "a\nb",
+            "a\r\nb",
+            "a\n\n\n",
+            "\n",
+            "",
+        ] {
+            let (lines, format) = split_lines(text);
+            assert_eq!(format.join(&lines), text);
+            assert_eq!(format.byte_len(&lines), text.len());
+        }
     }
 }
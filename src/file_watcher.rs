@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use iced::futures::channel::mpsc;
+use iced::futures::stream::{self, BoxStream, Stream, StreamExt};
+use iced::Subscription;
+use notify::{Config, Event, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher as _};
+
+/// The interval at which the files are checked when changes cannot be monitored
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Watch files for changes on disk
+///
+/// The directories of the files are monitored with the file system notifications of the platform,
+/// such as inotify on Linux, so that files that are replaced instead of written, as many editors
+/// do, are noticed as well. The files are polled when the notifications are not available.
+///
+/// # Example
+///
+/// ```rust
+/// let subscription: Subscription<String> = subscription(vec![String::from("/path/to/file.txt")]);
+/// ```
+///
+/// # Arguments
+///
+/// * `paths` - The paths of the files that are watched
+///
+/// # Returns
+///
+/// The `Subscription` that produces the path of a file whenever it changes
+pub fn subscription(paths: Vec<String>) -> Subscription<String> {
+    Subscription::from_recipe(Watcher { paths })
+}
+
+/// Produces the path of a watched file whenever it changes
+struct Watcher {
+    paths: Vec<String>,
+}
+
+impl<H: Hasher, I> iced_native::subscription::Recipe<H, I> for Watcher {
+    type Output = String;
+
+    fn hash(&self, state: &mut H) {
+        std::any::TypeId::of::<Self>().hash(state);
+        self.paths.hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<'static, I>) -> BoxStream<'static, String> {
+        match Changes::new(&self.paths) {
+            Some(d) => Box::pin(d),
+            None => Box::pin(stream::empty()),
+        }
+    }
+}
+
+/// The changes of the watched files
+///
+/// The files are only watched as long as the stream exists, so the notifications stop once the
+/// subscription is dropped.
+struct Changes {
+    _watcher: Box<dyn notify::Watcher + Send>,
+    receiver: mpsc::UnboundedReceiver<String>,
+}
+
+impl Changes {
+    /// Start watching the directories of the files
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let changes: Option<Changes> = Changes::new(&[String::from("/path/to/file.txt")]);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - The paths of the files that are watched
+    ///
+    /// # Returns
+    ///
+    /// The `Changes` of the files or `None` if none of the files can be watched
+    fn new(paths: &[String]) -> Option<Changes> {
+        // Events contain the canonical path of the directory followed by the name of the file
+        let files: HashMap<PathBuf, String> = paths
+            .iter()
+            .filter_map(|p| Some((std::fs::canonicalize(p).ok()?, p.clone())))
+            .collect();
+        let mut directories: Vec<PathBuf> = files
+            .keys()
+            .filter_map(|p| p.parent().map(Path::to_path_buf))
+            .collect();
+        directories.sort();
+        directories.dedup();
+
+        if directories.is_empty() {
+            return None;
+        }
+
+        let (sender, receiver) = mpsc::unbounded();
+        let handler = move |res: notify::Result<Event>| {
+            let event = match res {
+                Ok(d) if d.kind.is_modify() || d.kind.is_create() => d,
+                _ => return,
+            };
+
+            // Other files in the same directories are ignored
+            for path in event.paths.iter().filter_map(|p| files.get(p)) {
+                let _ = sender.unbounded_send(path.clone());
+            }
+        };
+
+        let mut watcher: Box<dyn notify::Watcher + Send> =
+            match RecommendedWatcher::new(handler.clone(), Config::default()) {
+                Ok(d) => Box::new(d),
+                Err(_) => Box::new(
+                    PollWatcher::new(handler, Config::default().with_poll_interval(POLL_INTERVAL))
+                        .ok()?,
+                ),
+            };
+
+        for directory in &directories {
+            let _ = watcher.watch(directory, RecursiveMode::NonRecursive);
+        }
+
+        Some(Changes {
+            _watcher: watcher,
+            receiver,
+        })
+    }
+}
+
+impl Stream for Changes {
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
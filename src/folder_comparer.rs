@@ -0,0 +1,188 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+
+/// A file that is missing from one of the folders or that differs between them
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FolderEntry {
+    FirstOnly(String),
+    SecondOnly(String),
+    Different(String),
+}
+
+impl FolderEntry {
+    /// Get the path of the file, relative to the compared folders
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::folder_comparer::FolderEntry;
+    /// let path: &str = FolderEntry::Different(String::from("src/main.rs")).path();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The relative path of the file
+    pub fn path(&self) -> &str {
+        match self {
+            FolderEntry::FirstOnly(d) | FolderEntry::SecondOnly(d) | FolderEntry::Different(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for FolderEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FolderEntry::FirstOnly(d) => write!(f, "Only in first folder: {}", d),
+            FolderEntry::SecondOnly(d) => write!(f, "Only in second folder: {}", d),
+            FolderEntry::Different(d) => write!(f, "Different: {}", d),
+        }
+    }
+}
+
+/// Compares the files of two folders and their subfolders
+#[derive(Debug, Clone)]
+pub struct FolderComparer {
+    pub first: PathBuf,
+    pub second: PathBuf,
+}
+
+impl FolderComparer {
+    /// Initialize a new `FolderComparer`
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The path of the first folder
+    /// * `second` - The path of the second folder
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::folder_comparer::FolderComparer;
+    /// let folder_comparer = FolderComparer::new("/path/to/first", "/path/to/second");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `FolderComparer` instance
+    pub fn new(first: &str, second: &str) -> FolderComparer {
+        FolderComparer {
+            first: PathBuf::from(first),
+            second: PathBuf::from(second),
+        }
+    }
+
+    /// Get the files that are missing from one of the folders or that have different contents
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use text_diff::folder_comparer::{FolderComparer, FolderEntry};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let folder_comparer = FolderComparer::new("/path/to/first", "/path/to/second");
+    /// let entries: Vec<FolderEntry> = folder_comparer.compare()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` of `FolderEntry` values, sorted by path, or an `Error` if a folder or file could
+    /// not be read
+    pub fn compare(&self) -> Result<Vec<FolderEntry>, Error> {
+        let first_files = list_files(&self.first)?;
+        let second_files = list_files(&self.second)?;
+
+        let mut entries = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < first_files.len() || j < second_files.len() {
+            match (first_files.get(i), second_files.get(j)) {
+                (Some(a), Some(b)) if a == b => {
+                    if read(&self.first.join(a))? != read(&self.second.join(b))? {
+                        entries.push(FolderEntry::Different(a.clone()));
+                    }
+                    i += 1;
+                    j += 1;
+                }
+                (Some(a), Some(b)) if a < b => {
+                    entries.push(FolderEntry::FirstOnly(a.clone()));
+                    i += 1;
+                }
+                (Some(a), None) => {
+                    entries.push(FolderEntry::FirstOnly(a.clone()));
+                    i += 1;
+                }
+                (_, Some(b)) => {
+                    entries.push(FolderEntry::SecondOnly(b.clone()));
+                    j += 1;
+                }
+                (None, None) => break,
+            }
+        }
+
+        Ok(entries)
+    }
+}
+
+/// List the files in a folder and its subfolders
+///
+/// # Returns
+///
+/// The sorted `Vec` of paths relative to the folder, using `/` as separator
+fn list_files(root: &Path) -> Result<Vec<String>, Error> {
+    let mut files = vec![];
+    let mut folders = vec![PathBuf::new()];
+
+    while let Some(folder) = folders.pop() {
+        let error = |e| Error::Read {
+            path: root.join(&folder).display().to_string(),
+            source: e,
+        };
+
+        let entries = match std::fs::read_dir(root.join(&folder)) {
+            Ok(d) => d,
+            Err(e) => return Err(error(e)),
+        };
+
+        for entry in entries {
+            let entry = match entry {
+                Ok(d) => d,
+                Err(e) => return Err(error(e)),
+            };
+
+            let file_type = match entry.file_type() {
+                Ok(d) => d,
+                Err(e) => return Err(error(e)),
+            };
+
+            let path = folder.join(entry.file_name());
+            if file_type.is_dir() {
+                folders.push(path);
+            } else if file_type.is_symlink() && entry.path().is_dir() {
+                // Linked folders are skipped, so that a cycle of links is not followed forever
+                continue;
+            } else {
+                let parts: Vec<_> = path
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect();
+                files.push(parts.join("/"));
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Read the contents of a file
+fn read(path: &Path) -> Result<Vec<u8>, Error> {
+    match std::fs::read(path) {
+        Ok(d) => Ok(d),
+        Err(e) => Err(Error::Read {
+            path: path.display().to_string(),
+            source: e,
+        }),
+    }
+}
@@ -0,0 +1,112 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Regular expressions for lines that should be left out of a comparison
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IgnoreRules {
+    pub patterns: Vec<String>,
+}
+
+impl IgnoreRules {
+    /// Initialize new `IgnoreRules`
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The regular expressions of the lines that should be ignored
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::ignore_rules::IgnoreRules;
+    /// let ignore_rules = IgnoreRules::new(vec![String::from("^\\s*//")]);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// New `IgnoreRules` for the given patterns
+    pub fn new(patterns: Vec<String>) -> IgnoreRules {
+        IgnoreRules { patterns }
+    }
+
+    /// Remove the lines that match any of the patterns
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The lines of a file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::ignore_rules::IgnoreRules;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let ignore_rules = IgnoreRules::new(vec![String::from("^#")]);
+    /// let lines: Vec<String> = ignore_rules.apply(vec![String::from("# comment")])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` that contains the lines that do not match any pattern or an `Error` if a
+    /// pattern is not a valid regular expression
+    pub fn apply(&self, lines: Vec<String>) -> Result<Vec<String>, Error> {
+        if self.patterns.is_empty() {
+            return Ok(lines);
+        }
+
+        let regexes = self.compile()?;
+        Ok(lines
+            .into_iter()
+            .filter(|l| !regexes.iter().any(|r| r.is_match(l)))
+            .collect())
+    }
+
+    /// Check which lines match any of the patterns
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The lines of a file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::ignore_rules::IgnoreRules;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let ignore_rules = IgnoreRules::new(vec![String::from("^#")]);
+    /// let ignored: Vec<bool> = ignore_rules.matches(&[String::from("# comment")])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` that tells for every line whether it should be ignored or an `Error` if a pattern
+    /// is not a valid regular expression
+    pub fn matches(&self, lines: &[String]) -> Result<Vec<bool>, Error> {
+        let regexes = self.compile()?;
+        Ok(lines
+            .iter()
+            .map(|l| regexes.iter().any(|r| r.is_match(l)))
+            .collect())
+    }
+
+    /// Compile the patterns into regular expressions
+    fn compile(&self) -> Result<Vec<Regex>, Error> {
+        let mut regexes = vec![];
+        for pattern in &self.patterns {
+            match Regex::new(pattern) {
+                Ok(d) => regexes.push(d),
+                Err(e) => {
+                    return Err(Error::InvalidPattern {
+                        pattern: pattern.clone(),
+                        message: e.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(regexes)
+    }
+}
@@ -0,0 +1,49 @@
+//! `text-diff` compares text files and reports the differences between them.
+//!
+//! The comparison logic can be used without the graphical user-interface by disabling the default
+//! `gui` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! text-diff = { version = "0.1", default-features = false }
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use text_diff::vector_comparer::{IVectorComparer, VectorComparer};
+//!
+//! let first = vec![String::from("a"), String::from("b")];
+//! let second = vec![String::from("a"), String::from("c")];
+//!
+//! let vector_comparer: VectorComparer<String> = IVectorComparer::<String>::new(first, second);
+//! let differences: Vec<String> = vector_comparer.get_differences();
+//! ```
+//!
+//! # Stability
+//!
+//! All public items of the modules below follow semantic versioning. Enums that are likely to gain
+//! variants, such as `Language` and `CompareMode`, are marked `#[non_exhaustive]`.
+
+/// Calculation of the shortest edit script between two slices
+pub mod diff;
+/// The error type of the crate
+pub mod error;
+/// Reading files from disk or from a local git repository
+pub mod file_reader;
+/// Comparison of the files in two folders
+pub mod folder_comparer;
+/// Lines that should be left out of a comparison
+pub mod ignore_rules;
+/// Three-way merging of two versions of a file
+pub mod merge;
+/// Statistics of the differences between two files
+pub mod statistics;
+/// Detection of programming languages and splitting source code into tokens
+pub mod syntax;
+/// Comparison of source files token by token
+pub mod token_comparer;
+/// Comparison of the lines of two files
+pub mod vector_comparer;
+/// Exporting differences to TXT, CSV or JSON files
+pub mod vector_exporter;
@@ -1,20 +1,70 @@
+use std::sync::atomic::Ordering;
+
 use iced::window;
-use iced::{Sandbox, Settings};
+use iced::{Application, Settings};
 
-mod file_reader;
+mod cli;
+mod file_watcher;
+mod notification;
+mod overview_ruler;
+mod session;
+mod settings;
 mod style;
-mod vector_comparer;
-mod vector_exporter;
+mod system_theme;
+mod text_area;
+mod timer;
 mod view;
+mod virtual_list;
 
 pub fn main() -> iced::Result {
+    let arguments = match cli::Arguments::parse(std::env::args().skip(1)) {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if arguments.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+
+    // Themes are loaded first, so that the theme in the settings can be found by its name
+    let mut errors = style::load_themes(settings::Settings::themes_path().as_deref());
+    if let Some(d) = system_theme::prefers_dark() {
+        style::set_prefers_dark(d);
+    }
+
+    if let Some(theme) = &arguments.theme {
+        if style::Theme::by_name(theme).is_none() {
+            eprintln!("Unknown theme: {}\n\n{}", theme, cli::USAGE);
+            std::process::exit(2);
+        }
+    }
+
+    let settings = match settings::Settings::load() {
+        Ok(d) => d,
+        Err(e) => {
+            errors.push(e);
+            settings::Settings::default()
+        }
+    };
+
     view::ApplicationContext::run(Settings {
         id: Some(String::from("text-diff")),
         window: window::Settings {
-            size: (800, 720),
+            size: settings.window_size,
             position: window::Position::Centered,
             ..window::Settings::default()
         },
-        ..Default::default()
-    })
+        exit_on_close_request: false,
+        ..Settings::with_flags(view::Flags {
+            arguments,
+            settings,
+            errors,
+        })
+    })?;
+
+    std::process::exit(view::EXIT_CODE.load(Ordering::SeqCst))
 }
@@ -0,0 +1,285 @@
+use crate::diff::{diff, hunks, Hunk};
+
+/// A part of the result of a three-way merge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeChunk {
+    Resolved(Vec<String>),
+    Conflict {
+        base: Vec<String>,
+        local: Vec<String>,
+        remote: Vec<String>,
+    },
+}
+
+/// The way a conflicting `MergeChunk` is resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    Local,
+    Remote,
+    Both,
+}
+
+impl MergeChunk {
+    /// Get the lines of the chunk for the given `Resolution`
+    ///
+    /// # Arguments
+    ///
+    /// * `resolution` - The `Resolution` of the chunk, which is ignored for resolved chunks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::merge::{MergeChunk, Resolution};
+    /// # let chunk = MergeChunk::Resolved(vec![]);
+    /// let lines: Vec<String> = chunk.resolve(Resolution::Local);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` that contains the lines of the chunk
+    pub fn resolve(&self, resolution: Resolution) -> Vec<String> {
+        match self {
+            MergeChunk::Resolved(lines) => lines.clone(),
+            MergeChunk::Conflict { local, remote, .. } => match resolution {
+                Resolution::Local => local.clone(),
+                Resolution::Remote => remote.clone(),
+                Resolution::Both => local.iter().chain(remote.iter()).cloned().collect(),
+            },
+        }
+    }
+}
+
+/// Merges the changes of two versions of a file to their common ancestor
+#[derive(Debug, Clone)]
+pub struct ThreeWayMerger {
+    pub base: Vec<String>,
+    pub local: Vec<String>,
+    pub remote: Vec<String>,
+}
+
+impl ThreeWayMerger {
+    /// Initialize a new `ThreeWayMerger`
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The lines of the common ancestor
+    /// * `local` - The lines of the local version
+    /// * `remote` - The lines of the remote version
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::merge::ThreeWayMerger;
+    /// let merger = ThreeWayMerger::new(vec![], vec![], vec![]);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `ThreeWayMerger` instance
+    pub fn new(base: Vec<String>, local: Vec<String>, remote: Vec<String>) -> ThreeWayMerger {
+        ThreeWayMerger {
+            base,
+            local,
+            remote,
+        }
+    }
+
+    /// Merge the local and remote changes to the base
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::merge::{MergeChunk, ThreeWayMerger};
+    /// # let merger = ThreeWayMerger::new(vec![], vec![], vec![]);
+    /// let chunks: Vec<MergeChunk> = merger.merge();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` of `MergeChunk` values that make up the merged result, with a conflict for every
+    /// region of the base that was changed differently on both sides
+    pub fn merge(&self) -> Vec<MergeChunk> {
+        let local_hunks = hunks(&diff(&self.base, &self.local));
+        let remote_hunks = hunks(&diff(&self.base, &self.remote));
+
+        let mut chunks = vec![];
+        let mut base_position = 0;
+        let mut local_offset: isize = 0;
+        let mut remote_offset: isize = 0;
+        let (mut l, mut r) = (0, 0);
+
+        while l < local_hunks.len() || r < remote_hunks.len() {
+            let region_start = match (local_hunks.get(l), remote_hunks.get(r)) {
+                (Some(a), Some(b)) => usize::min(a.old_index, b.old_index),
+                (Some(a), None) => a.old_index,
+                (None, Some(b)) => b.old_index,
+                (None, None) => break,
+            };
+
+            // Collect all hunks of both sides that overlap or touch the region
+            let (first_l, first_r) = (l, r);
+            let mut region_end = region_start;
+            loop {
+                if let Some(h) = local_hunks.get(l).filter(|h| h.old_index <= region_end) {
+                    region_end = usize::max(region_end, h.old_index + h.old_len);
+                    l += 1;
+                } else if let Some(h) = remote_hunks.get(r).filter(|h| h.old_index <= region_end) {
+                    region_end = usize::max(region_end, h.old_index + h.old_len);
+                    r += 1;
+                } else {
+                    break;
+                }
+            }
+
+            push_resolved(&mut chunks, &self.base[base_position..region_start]);
+
+            let (local_start, local_end) = side_range(
+                &local_hunks[first_l..l],
+                region_start,
+                region_end,
+                &mut local_offset,
+            );
+            let (remote_start, remote_end) = side_range(
+                &remote_hunks[first_r..r],
+                region_start,
+                region_end,
+                &mut remote_offset,
+            );
+
+            let local = &self.local[local_start..local_end];
+            let remote = &self.remote[remote_start..remote_end];
+
+            if first_r == r || local == remote {
+                push_resolved(&mut chunks, local);
+            } else if first_l == l {
+                push_resolved(&mut chunks, remote);
+            } else {
+                chunks.push(MergeChunk::Conflict {
+                    base: self.base[region_start..region_end].to_vec(),
+                    local: local.to_vec(),
+                    remote: remote.to_vec(),
+                });
+            }
+
+            base_position = region_end;
+        }
+
+        push_resolved(&mut chunks, &self.base[base_position..]);
+
+        chunks
+    }
+}
+
+/// Map a region of the base to the matching range of one side
+///
+/// The offset between the base and the side is updated to the value after the region.
+fn side_range(
+    hunks: &[Hunk],
+    region_start: usize,
+    region_end: usize,
+    offset: &mut isize,
+) -> (usize, usize) {
+    let (first, last) = match (hunks.first(), hunks.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => {
+            return (
+                (region_start as isize + *offset) as usize,
+                (region_end as isize + *offset) as usize,
+            )
+        }
+    };
+
+    let (last_base_end, last_side_end) =
+        (last.old_index + last.old_len, last.new_index + last.new_len);
+    *offset = last_side_end as isize - last_base_end as isize;
+
+    (
+        first.new_index - (first.old_index - region_start),
+        last_side_end + (region_end - last_base_end),
+    )
+}
+
+/// Append resolved lines to the chunks, joining them with a previous resolved chunk
+fn push_resolved(chunks: &mut Vec<MergeChunk>, lines: &[String]) {
+    if lines.is_empty() {
+        return;
+    }
+
+    match chunks.last_mut() {
+        Some(MergeChunk::Resolved(resolved)) => resolved.extend_from_slice(lines),
+        _ => chunks.push(MergeChunk::Resolved(lines.to_vec())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    fn merge(base: &str, local: &str, remote: &str) -> Vec<MergeChunk> {
+        ThreeWayMerger::new(lines(base), lines(local), lines(remote)).merge()
+    }
+
+    #[test]
+    fn changes_in_separate_regions_are_merged() {
+        let chunks = merge("a b c d e", "A b c d e", "a b c d E");
+        assert_eq!(chunks, vec![MergeChunk::Resolved(lines("A b c d E"))]);
+    }
+
+    #[test]
+    fn identical_changes_are_merged() {
+        let chunks = merge("a b c", "a B c", "a B c");
+        assert_eq!(chunks, vec![MergeChunk::Resolved(lines("a B c"))]);
+    }
+
+    #[test]
+    fn different_changes_of_the_same_lines_conflict() {
+        let chunks = merge("a b c", "a L c", "a R c");
+        assert_eq!(
+            chunks,
+            vec![
+                MergeChunk::Resolved(lines("a")),
+                MergeChunk::Conflict {
+                    base: lines("b"),
+                    local: lines("L"),
+                    remote: lines("R"),
+                },
+                MergeChunk::Resolved(lines("c")),
+            ]
+        );
+    }
+
+    #[test]
+    fn adjacent_changes_conflict() {
+        let chunks = merge("a b c d", "a L c d", "a b R d");
+        assert_eq!(
+            chunks,
+            vec![
+                MergeChunk::Resolved(lines("a")),
+                MergeChunk::Conflict {
+                    base: lines("b c"),
+                    local: lines("L c"),
+                    remote: lines("b R"),
+                },
+                MergeChunk::Resolved(lines("d")),
+            ]
+        );
+    }
+
+    #[test]
+    fn a_deletion_conflicts_with_a_change() {
+        let chunks = merge("a b c", "a c", "a B c");
+        assert_eq!(
+            chunks[1],
+            MergeChunk::Conflict {
+                base: lines("b"),
+                local: vec![],
+                remote: lines("B"),
+            }
+        );
+        assert_eq!(chunks[1].resolve(Resolution::Both), lines("B"));
+    }
+}
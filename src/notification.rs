@@ -0,0 +1,133 @@
+use iced::{alignment, button, Alignment, Button, Column, Container, Length, Row, Text};
+
+use crate::style;
+use crate::view::Message;
+
+/// The maximum number of notifications that are displayed at the same time
+const MAX_NOTIFICATIONS: usize = 5;
+
+/// The severity of a `Notification`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "Info"),
+            Severity::Warning => write!(f, "Warning"),
+            Severity::Error => write!(f, "Error"),
+        }
+    }
+}
+
+/// A message that is displayed in the notification area of the window until it is dismissed
+pub struct Notification {
+    pub id: usize,
+    pub severity: Severity,
+    pub message: String,
+    pub btn_dismiss: button::State,
+}
+
+/// The notifications that are displayed in the window
+#[derive(Default)]
+pub struct Notifications {
+    pub items: Vec<Notification>,
+    next_id: usize,
+}
+
+impl Notifications {
+    /// Add a notification, removing the oldest one when there are too many
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// notifications.push(Severity::Info, "hello")
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - The `Severity` of the notification
+    /// * `message` - The message of the notification
+    pub fn push(&mut self, severity: Severity, message: &str) {
+        if self.items.len() == MAX_NOTIFICATIONS {
+            self.items.remove(0);
+        }
+
+        self.items.push(Notification {
+            id: self.next_id,
+            severity,
+            message: String::from(message),
+            btn_dismiss: button::State::default(),
+        });
+        self.next_id += 1;
+    }
+
+    /// Remove a notification
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// notifications.dismiss(0)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the notification that should be removed
+    pub fn dismiss(&mut self, id: usize) {
+        self.items.retain(|n| n.id != id);
+    }
+
+    /// Create the notification area
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let column: Column<'_, Message> = notifications.view(Theme::Dark)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The `Theme` of the dismiss buttons
+    ///
+    /// # Returns
+    ///
+    /// The `Column` that contains a row for every notification, with the newest one at the top
+    pub fn view(&mut self, theme: style::Theme) -> Column<'_, Message> {
+        self.items
+            .iter_mut()
+            .rev()
+            .fold(Column::new().spacing(5), |column, notification| {
+                let btn_dismiss = Button::new(
+                    &mut notification.btn_dismiss,
+                    Text::new("Dismiss").horizontal_alignment(alignment::Horizontal::Center),
+                )
+                .padding(5)
+                .min_width(100)
+                .on_press(Message::NotificationDismissed(notification.id))
+                .style(theme);
+
+                let row = Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(
+                        Text::new(format!(
+                            "{}: {}",
+                            notification.severity, notification.message
+                        ))
+                        .width(Length::Fill),
+                    )
+                    .push(btn_dismiss);
+
+                column.push(
+                    Container::new(row)
+                        .width(Length::Fill)
+                        .padding(10)
+                        .style(style::Notification(notification.severity)),
+                )
+            })
+    }
+}
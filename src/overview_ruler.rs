@@ -0,0 +1,81 @@
+use iced::{button, Button, Color, Column, Length, Space};
+
+use crate::style;
+use crate::view::Message;
+
+/// The height of a segment of the ruler, in pixels
+const SEGMENT_HEIGHT: u16 = 5;
+
+/// The width of the ruler, in pixels
+const WIDTH: u16 = 14;
+
+/// A narrow strip that shows where the changes are located in the compared files
+///
+/// The strip is divided into segments that each cover an equal part of the lines. A segment that
+/// contains a change is colored like the change and pressing a segment jumps to it.
+#[derive(Default)]
+pub struct OverviewRuler {
+    segments: Vec<button::State>,
+}
+
+impl OverviewRuler {
+    /// Create the view of the ruler
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let element: Column<'_, Message> = overview_ruler.view(&[(12, Color::WHITE)], 100, 300, theme)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `marks` - The lines that contain a change and the `Color` of the change, in order
+    /// * `len` - The number of lines
+    /// * `height` - The height of the ruler, in pixels
+    /// * `theme` - The `Theme` of the ruler
+    ///
+    /// # Returns
+    ///
+    /// The `Column` that contains a button for every segment of the ruler
+    pub fn view(
+        &mut self,
+        marks: &[(usize, Color)],
+        len: usize,
+        height: u16,
+        theme: style::Theme,
+    ) -> Column<'_, Message> {
+        let count = usize::max((height / SEGMENT_HEIGHT) as usize, 1);
+        self.segments.resize_with(count, button::State::default);
+
+        let mut marks = marks.iter().peekable();
+        let mut column = Column::new().width(Length::Units(WIDTH));
+        for (index, state) in self.segments.iter_mut().enumerate() {
+            let start = index * len / count;
+            let end = (index + 1) * len / count;
+
+            // Every mark belongs to the segment that covers its line, even when segments are
+            // smaller than a line
+            let mut mark: Option<(usize, Color)> = None;
+            while let Some(&&(line, color)) = marks.peek() {
+                if line >= end && index + 1 < count {
+                    break;
+                }
+                mark = mark.or(Some((line, color)));
+                marks.next();
+            }
+
+            let target = mark.map_or(start, |(line, _)| line);
+            let mut button = Button::new(state, Space::new(Length::Fill, Length::Fill))
+                .width(Length::Fill)
+                .height(Length::Units(SEGMENT_HEIGHT))
+                .padding(0)
+                .style(style::OverviewMark(theme, mark.map(|(_, color)| color)));
+            if target < len {
+                button = button.on_press(Message::OverviewPressed(target));
+            }
+            column = column.push(button);
+        }
+
+        column
+    }
+}
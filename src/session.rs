@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use text_diff::error::Error;
+
+use crate::settings::TabSettings;
+
+/// The extension of session files
+pub const EXTENSION: &str = "tdiff";
+
+/// The version of the session format that is written
+const VERSION: u32 = 1;
+
+/// A comparison that is stored in a session file, so that it can be opened again exactly as it was
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    pub version: u32,
+    #[serde(flatten)]
+    pub tab: TabSettings,
+    pub context_lines: usize,
+    pub search: String,
+    pub current_change: Option<usize>,
+    pub scroll_position: usize,
+}
+
+impl Session {
+    /// Load a session from a session file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let session: Result<Session, Error> = Session::load("/path/to/session.tdiff");
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the session file
+    ///
+    /// # Returns
+    ///
+    /// The stored `Session` or an `Error` if the session file could not be read or was written by
+    /// a newer version
+    pub fn load(path: &str) -> Result<Session, Error> {
+        let data = match std::fs::read_to_string(path) {
+            Ok(d) => d,
+            Err(e) => {
+                return Err(Error::Read {
+                    path: String::from(path),
+                    source: e,
+                })
+            }
+        };
+
+        let session: Session = match serde_json::from_str(&data) {
+            Ok(d) => d,
+            Err(e) => {
+                return Err(Error::Parse {
+                    path: String::from(path),
+                    message: e.to_string(),
+                })
+            }
+        };
+
+        if session.version > VERSION {
+            return Err(Error::Parse {
+                path: String::from(path),
+                message: format!("unsupported session version {}", session.version),
+            });
+        }
+
+        Ok(session)
+    }
+
+    /// Save the session to a session file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let res: Result<(), Error> = session.save("/path/to/session.tdiff");
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the session file
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that can either contain an `Ok` or an `Error` if the session could not be written
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        let error = |source: std::io::Error| Error::Write {
+            path: String::from(path),
+            source,
+        };
+
+        let session = Session {
+            version: VERSION,
+            ..self.clone()
+        };
+        let data = match serde_json::to_string_pretty(&session) {
+            Ok(d) => d,
+            Err(e) => return Err(error(e.into())),
+        };
+
+        match std::fs::write(path, data) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(error(e)),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use text_diff::error::Error;
+use text_diff::file_reader::Revision;
+use text_diff::ignore_rules::IgnoreRules;
+use text_diff::vector_comparer::CompareMode;
+
+use crate::style::Theme;
+use crate::view::{Filter, InputMode, ViewMode};
+
+/// The maximum number of file pairs in the most-recently-used list
+const MAX_RECENT: usize = 10;
+
+/// A pair of files that were compared
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentPair {
+    pub first_file: String,
+    pub second_file: String,
+}
+
+impl std::fmt::Display for RecentPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ↔ {}", self.first_file, self.second_file)
+    }
+}
+
+/// A comparison tab that is restored when the application is started again
+///
+/// The revisions are stored by their name, such as `index` or `HEAD~1`, and the texts are only
+/// stored for comparisons of texts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TabSettings {
+    pub input_mode: InputMode,
+    pub first_file: String,
+    pub second_file: String,
+    pub first_revision: String,
+    pub second_revision: String,
+    pub first_text: Vec<String>,
+    pub second_text: Vec<String>,
+    pub compare_mode: CompareMode,
+    pub ignore_rules: IgnoreRules,
+    pub view_mode: ViewMode,
+    pub filter: Filter,
+    pub has_compared: bool,
+}
+
+/// The settings of the user, which are kept between sessions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub theme: Theme,
+    pub compare_mode: CompareMode,
+    pub ignore_rules: IgnoreRules,
+    pub native_dialogs: bool,
+    pub auto_reload: bool,
+    pub view_mode: ViewMode,
+    pub context_lines: usize,
+    pub window_size: (u32, u32),
+    pub recent: Vec<RecentPair>,
+    pub tabs: Vec<TabSettings>,
+    pub active_tab: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            theme: Theme::default(),
+            compare_mode: CompareMode::default(),
+            ignore_rules: IgnoreRules::default(),
+            native_dialogs: false,
+            auto_reload: true,
+            view_mode: ViewMode::default(),
+            context_lines: 3,
+            window_size: (800, 720),
+            recent: vec![],
+            tabs: vec![],
+            active_tab: 0,
+        }
+    }
+}
+
+impl Settings {
+    /// Get the path of the settings file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let path: Option<PathBuf> = Settings::path();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The path of `settings.json` in the `text-diff` directory of the configuration directory of
+    /// the user, or `None` if the configuration directory is unknown
+    pub fn path() -> Option<PathBuf> {
+        config_dir().map(|d| d.join("text-diff").join("settings.json"))
+    }
+
+    /// Get the directory that contains the themes of the user
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let path: Option<PathBuf> = Settings::themes_path();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The path of the `themes` directory in the `text-diff` directory of the configuration
+    /// directory of the user, or `None` if the configuration directory is unknown
+    pub fn themes_path() -> Option<PathBuf> {
+        config_dir().map(|d| d.join("text-diff").join("themes"))
+    }
+
+    /// Load the settings from the settings file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let settings: Result<Settings, Error> = Settings::load();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The stored `Settings`, the default `Settings` if no settings were stored yet or an `Error`
+    /// if the settings file could not be read
+    pub fn load() -> Result<Settings, Error> {
+        let path = match Settings::path() {
+            Some(d) if d.exists() => d,
+            _ => return Ok(Settings::default()),
+        };
+
+        let data = match std::fs::read_to_string(&path) {
+            Ok(d) => d,
+            Err(e) => {
+                return Err(Error::Read {
+                    path: path.display().to_string(),
+                    source: e,
+                })
+            }
+        };
+
+        match serde_json::from_str(&data) {
+            Ok(d) => Ok(d),
+            Err(e) => Err(Error::Parse {
+                path: path.display().to_string(),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    /// Save the settings to the settings file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let res: Result<(), Error> = settings.save();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that can either contain an `Ok` or an `Error` if the settings could not be written
+    pub fn save(&self) -> Result<(), Error> {
+        let path = match Settings::path() {
+            Some(d) => d,
+            None => return Ok(()),
+        };
+
+        let error = |source: std::io::Error| Error::Write {
+            path: path.display().to_string(),
+            source,
+        };
+
+        if let Some(parent) = path.parent() {
+            if let Err(e) = std::fs::create_dir_all(parent) {
+                return Err(error(e));
+            }
+        }
+
+        let data = match serde_json::to_string_pretty(self) {
+            Ok(d) => d,
+            Err(e) => return Err(error(e.into())),
+        };
+
+        match std::fs::write(&path, data) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(error(e)),
+        }
+    }
+
+    /// Move a pair of files to the top of the most-recently-used list
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// settings.add_recent("/path/to/first/file.txt", "/path/to/second/file.txt");
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `first_file` - The path of the first file
+    /// * `second_file` - The path of the second file
+    pub fn add_recent(&mut self, first_file: &str, second_file: &str) {
+        let pair = RecentPair {
+            first_file: String::from(first_file),
+            second_file: String::from(second_file),
+        };
+
+        self.recent.retain(|p| *p != pair);
+        self.recent.insert(0, pair);
+        self.recent.truncate(MAX_RECENT);
+    }
+}
+
+/// Get the configuration directory of the user
+///
+/// `XDG_CONFIG_HOME` is used when it is set, otherwise the default location of the platform.
+fn config_dir() -> Option<PathBuf> {
+    if let Some(d) = std::env::var_os("XDG_CONFIG_HOME").filter(|d| !d.is_empty()) {
+        return Some(PathBuf::from(d));
+    }
+
+    if cfg!(windows) {
+        return std::env::var_os("APPDATA").map(PathBuf::from);
+    }
+
+    let home = PathBuf::from(std::env::var_os("HOME")?);
+    if cfg!(target_os = "macos") {
+        Some(home.join("Library").join("Application Support"))
+    } else {
+        Some(home.join(".config"))
+    }
+}
+
+/// Get the name of a revision that can be parsed by `Revision::parse`
+///
+/// # Example
+///
+/// ```rust
+/// let name: String = revision_name(&Revision::Index);
+/// ```
+///
+/// # Arguments
+///
+/// * `revision` - The `Revision`
+///
+/// # Returns
+///
+/// `index` for the staged version, the revision of a commit or an empty `String` for the working
+/// tree
+pub fn revision_name(revision: &Revision) -> String {
+    match revision {
+        Revision::WorkingTree => String::new(),
+        Revision::Index => String::from("index"),
+        Revision::Commit { rev, .. } => rev.clone(),
+    }
+}
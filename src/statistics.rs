@@ -0,0 +1,224 @@
+use serde::Serialize;
+
+use crate::diff::{diff, hunks, Hunk};
+use crate::file_reader::LineFormat;
+
+/// A summary of the differences between two files
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct Statistics {
+    pub first_lines: usize,
+    pub second_lines: usize,
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    pub unchanged: usize,
+    pub similarity: f64,
+    pub hunks: usize,
+    pub first_bytes: usize,
+    pub second_bytes: usize,
+}
+
+impl Statistics {
+    /// Calculate the statistics of the differences between the lines of two files
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The lines of the first file
+    /// * `second` - The lines of the second file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::statistics::Statistics;
+    /// let first = vec![String::from("a"), String::from("b")];
+    /// let second = vec![String::from("a"), String::from("c"), String::from("d")];
+    ///
+    /// let statistics: Statistics = Statistics::new(&first, &second);
+    /// assert_eq!(statistics.changed, 1);
+    /// assert_eq!(statistics.added, 1);
+    /// assert_eq!(statistics.similarity, 40.0);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Statistics` of the differences
+    pub fn new(first: &[String], second: &[String]) -> Statistics {
+        Statistics::from_hunks(first, second, &hunks(&diff(first, second)))
+    }
+
+    /// Calculate the statistics of hunks that were already found between the lines of two files
+    ///
+    /// The lines that are not part of any hunk are counted as unchanged. Of every hunk, as many
+    /// lines as both sides have in common are counted as changed and the remaining lines as
+    /// removed or added.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The lines of the first file
+    /// * `second` - The lines of the second file
+    /// * `hunks` - The hunks between both files
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::diff::{diff, hunks};
+    /// # use text_diff::statistics::Statistics;
+    /// # let first = vec![String::from("a")];
+    /// # let second = vec![String::from("b")];
+    /// let statistics: Statistics = Statistics::from_hunks(&first, &second, &hunks(&diff(&first, &second)));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Statistics` of the hunks
+    pub fn from_hunks(first: &[String], second: &[String], hunks: &[Hunk]) -> Statistics {
+        let mut statistics = Statistics {
+            first_lines: first.len(),
+            second_lines: second.len(),
+            hunks: hunks.len(),
+            first_bytes: bytes(first),
+            second_bytes: bytes(second),
+            ..Statistics::default()
+        };
+
+        for hunk in hunks {
+            let changed = usize::min(hunk.old_len, hunk.new_len);
+            statistics.changed += changed;
+            statistics.removed += hunk.old_len - changed;
+            statistics.added += hunk.new_len - changed;
+        }
+
+        statistics.unchanged = first
+            .len()
+            .saturating_sub(statistics.changed + statistics.removed);
+
+        let total = first.len() + second.len();
+        statistics.similarity = if total == 0 {
+            100.0
+        } else {
+            (2 * statistics.unchanged) as f64 * 100.0 / total as f64
+        };
+
+        statistics
+    }
+
+    /// Set the exact sizes of both files
+    ///
+    /// The sizes that are calculated from the lines assume a line feed after every line, which
+    /// differs from the files when they use other line endings or do not end with a line break.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_bytes` - The number of bytes of the first file
+    /// * `second_bytes` - The number of bytes of the second file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::statistics::Statistics;
+    /// let statistics: Statistics = Statistics::default().with_bytes(5, 6);
+    /// assert_eq!(statistics.first_bytes, 5);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Statistics` with the given sizes
+    pub fn with_bytes(mut self, first_bytes: usize, second_bytes: usize) -> Statistics {
+        self.first_bytes = first_bytes;
+        self.second_bytes = second_bytes;
+        self
+    }
+
+    /// Get the statistics as labeled values, in the order in which they are displayed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::statistics::Statistics;
+    /// # let statistics = Statistics::default();
+    /// for (label, value) in statistics.entries() {
+    ///     println!("{}: {}", label, value);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` that contains the label and the formatted value of every statistic
+    pub fn entries(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("Lines in the first file", self.first_lines.to_string()),
+            ("Lines in the second file", self.second_lines.to_string()),
+            ("Added lines", self.added.to_string()),
+            ("Removed lines", self.removed.to_string()),
+            ("Changed lines", self.changed.to_string()),
+            ("Unchanged lines", self.unchanged.to_string()),
+            ("Similarity", format!("{:.1}%", self.similarity)),
+            ("Hunks", self.hunks.to_string()),
+            ("Bytes in the first file", self.first_bytes.to_string()),
+            ("Bytes in the second file", self.second_bytes.to_string()),
+        ]
+    }
+}
+
+/// Count the bytes of lines, including a line feed after every line
+fn bytes(lines: &[String]) -> usize {
+    LineFormat::default().byte_len(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| String::from(*l)).collect()
+    }
+
+    #[test]
+    fn empty_files_are_identical() {
+        let statistics = Statistics::new(&[], &[]);
+        assert_eq!(
+            statistics,
+            Statistics {
+                similarity: 100.0,
+                ..Statistics::default()
+            }
+        );
+    }
+
+    #[test]
+    fn identical_files_have_no_changes() {
+        let first = lines(&["a", "b"]);
+        let statistics = Statistics::new(&first, &first);
+        assert_eq!(statistics.unchanged, 2);
+        assert_eq!(statistics.hunks, 0);
+        assert_eq!(statistics.similarity, 100.0);
+    }
+
+    #[test]
+    fn all_lines_changed() {
+        let statistics = Statistics::new(&lines(&["a", "b", "c"]), &lines(&["d", "e", "f"]));
+        assert_eq!(statistics.changed, 3);
+        assert_eq!(statistics.added, 0);
+        assert_eq!(statistics.removed, 0);
+        assert_eq!(statistics.unchanged, 0);
+        assert_eq!(statistics.hunks, 1);
+        assert_eq!(statistics.similarity, 0.0);
+    }
+
+    #[test]
+    fn lines_added_to_an_empty_file() {
+        let statistics = Statistics::new(&[], &lines(&["a", "b"]));
+        assert_eq!(statistics.added, 2);
+        assert_eq!(statistics.changed, 0);
+        assert_eq!(statistics.similarity, 0.0);
+        assert_eq!(statistics.second_bytes, 4);
+    }
+
+    #[test]
+    fn exact_sizes_replace_the_calculated_sizes() {
+        let first = lines(&["a", "b"]);
+        let statistics = Statistics::new(&first, &first).with_bytes(4, 3);
+        assert_eq!(statistics.first_bytes, 4);
+        assert_eq!(statistics.second_bytes, 3);
+    }
+}
@@ -1,208 +1,700 @@
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
 use iced::{
-    button, checkbox, container, progress_bar, radio, rule, scrollable, slider, text_input, toggler,
+    button, checkbox, container, pick_list, progress_bar, radio, rule, scrollable, slider,
+    text_input, toggler, Color,
 };
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use text_diff::error::Error;
+use text_diff::syntax::TokenKind;
+
+use crate::notification::Severity;
+
+/// The themes that are bundled with the application
+const BUNDLED_THEMES: [(&str, &str); 4] = [
+    ("themes/light.toml", include_str!("../themes/light.toml")),
+    ("themes/dark.toml", include_str!("../themes/dark.toml")),
+    (
+        "themes/high-contrast.toml",
+        include_str!("../themes/high-contrast.toml"),
+    ),
+    (
+        "themes/colorblind-safe.toml",
+        include_str!("../themes/colorblind-safe.toml"),
+    ),
+];
+
+/// The themes that were discovered on startup
+static THEMES: OnceLock<Vec<ThemeDefinition>> = OnceLock::new();
+
+/// Whether the desktop prefers a dark color scheme, which selects the theme of `Theme::System`
+static PREFERS_DARK: AtomicBool = AtomicBool::new(true);
+
+/// Set the color scheme preference of the desktop, which is followed by `Theme::System`
+///
+/// # Arguments
+///
+/// * `prefers_dark` - Whether the desktop prefers a dark color scheme
+///
+/// # Example
+///
+/// ```rust
+/// set_prefers_dark(true);
+/// ```
+pub fn set_prefers_dark(prefers_dark: bool) {
+    PREFERS_DARK.store(prefers_dark, Ordering::SeqCst);
+}
+
+/// A color that is written as `#RRGGBB` or `#RRGGBBAA` in a theme file
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct HexColor(pub Color);
+
+impl TryFrom<String> for HexColor {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let hex = value.trim_start_matches('#');
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .ok_or_else(|| format!("Invalid color: {}", value))
+        };
+
+        let alpha = match hex.len() {
+            6 => 0xFF,
+            8 => channel(6)?,
+            _ => return Err(format!("Invalid color: {}", value)),
+        };
+
+        Ok(HexColor(Color::from_rgba8(
+            channel(0)?,
+            channel(2)?,
+            channel(4)?,
+            alpha as f32 / 255.0,
+        )))
+    }
+}
+
+/// The colors of the widgets of a theme
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Palette {
+    pub background: HexColor,
+    pub text: HexColor,
+    pub surface: HexColor,
+    pub accent: HexColor,
+    pub active: HexColor,
+    pub hovered: HexColor,
+    pub active_text: HexColor,
+    pub placeholder: HexColor,
+}
+
+/// The colors of the lines that were added, removed or changed
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct DiffColors {
+    pub added: HexColor,
+    pub removed: HexColor,
+    pub changed: HexColor,
+}
+
+/// The colors of the tokens of source code, identifiers use the text color of the palette
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct SyntaxColors {
+    pub keyword: HexColor,
+    pub number: HexColor,
+    pub string: HexColor,
+    pub comment: HexColor,
+    pub punctuation: HexColor,
+}
+
+/// The font sizes of a theme
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct FontSizes {
+    pub title: u16,
+    pub heading: u16,
+    pub text: u16,
+}
+
+impl Default for FontSizes {
+    fn default() -> Self {
+        FontSizes {
+            title: 80,
+            heading: 30,
+            text: 20,
+        }
+    }
+}
+
+/// A theme as it is defined in a TOML or JSON file
+#[derive(Debug, Clone, Deserialize)]
+pub struct ThemeDefinition {
+    pub name: String,
+    pub palette: Palette,
+    pub diff: DiffColors,
+    pub syntax: SyntaxColors,
+    #[serde(default)]
+    pub font_sizes: FontSizes,
+}
+
+impl ThemeDefinition {
+    /// Parse a theme definition
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the theme file, the extension selects between TOML and JSON
+    /// * `data` - The contents of the theme file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let definition = ThemeDefinition::parse("dark.toml", "name = \"Dark\"");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The parsed `ThemeDefinition` or an `Error` if the contents are not a valid theme
+    pub fn parse(path: &str, data: &str) -> Result<ThemeDefinition, Error> {
+        let res = if path.to_lowercase().ends_with(".json") {
+            serde_json::from_str(data).map_err(|e| e.to_string())
+        } else {
+            toml::from_str(data).map_err(|e| e.to_string())
+        };
+
+        match res {
+            Ok(d) => Ok(d),
+            Err(e) => Err(Error::Parse {
+                path: String::from(path),
+                message: e,
+            }),
+        }
+    }
+}
+
+/// Load the bundled themes and the themes in the given directory
+///
+/// A theme in the directory replaces a bundled theme with the same name. This should be called
+/// once on startup, before any `Theme` is used.
+///
+/// # Arguments
+///
+/// * `directory` - The optional directory that contains `.toml` and `.json` theme files
+///
+/// # Example
+///
+/// ```rust
+/// let errors: Vec<Error> = load_themes(Some(Path::new("/path/to/themes")));
+/// ```
+///
+/// # Returns
+///
+/// The `Vec` of errors for the theme files that could not be loaded
+pub fn load_themes(directory: Option<&Path>) -> Vec<Error> {
+    let mut themes = bundled_themes();
+    let mut errors = vec![];
+
+    let entries = match directory.map(std::fs::read_dir) {
+        Some(Ok(d)) => d,
+        _ => {
+            let _ = THEMES.set(themes);
+            return errors;
+        }
+    };
+
+    let mut paths: Vec<_> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    paths.sort();
+
+    for path in paths {
+        let is_theme = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("toml") || e.eq_ignore_ascii_case("json"));
+        if !is_theme {
+            continue;
+        }
+
+        let display = path.display().to_string();
+        let definition = match std::fs::read_to_string(&path) {
+            Ok(d) => ThemeDefinition::parse(&display, &d),
+            Err(e) => Err(Error::Read {
+                path: display,
+                source: e,
+            }),
+        };
+
+        match definition {
+            Ok(d) => match themes.iter_mut().find(|t| t.name == d.name) {
+                Some(t) => *t = d,
+                None => themes.push(d),
+            },
+            Err(e) => errors.push(e),
+        }
+    }
+
+    let _ = THEMES.set(themes);
+    errors
+}
+
+/// Parse the themes that are bundled with the application
+fn bundled_themes() -> Vec<ThemeDefinition> {
+    BUNDLED_THEMES
+        .iter()
+        .filter_map(|(path, data)| ThemeDefinition::parse(path, data).ok())
+        .collect()
+}
+
+/// Get the discovered themes, or only the bundled themes if no themes were loaded
+fn definitions() -> &'static [ThemeDefinition] {
+    THEMES.get_or_init(bundled_themes)
+}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// One of the discovered themes or the theme that follows the preference of the desktop
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum Theme {
-    Dark,
-    Light,
+    #[default]
+    System,
+    Defined(usize),
 }
 
 impl Theme {
-    pub const ALL: [Theme; 2] = [Theme::Light, Theme::Dark];
+    /// Get all the discovered themes
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let themes: Vec<Theme> = Theme::all();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` that contains `Theme::System` and a `Theme` for every discovered theme
+    pub fn all() -> Vec<Theme> {
+        std::iter::once(Theme::System)
+            .chain((0..definitions().len()).map(Theme::Defined))
+            .collect()
+    }
+
+    /// Find a theme by its name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the theme, which is compared case-insensitively
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let theme: Option<Theme> = Theme::by_name("Dark");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Theme` with the given name or `None` if no such theme was discovered
+    pub fn by_name(name: &str) -> Option<Theme> {
+        if name.eq_ignore_ascii_case("System") {
+            return Some(Theme::System);
+        }
+
+        definitions()
+            .iter()
+            .position(|d| d.name.eq_ignore_ascii_case(name))
+            .map(Theme::Defined)
+    }
+
+    /// Get the definition of the theme
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let definition: &ThemeDefinition = Theme::default().definition();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `ThemeDefinition` that was loaded for the theme, which is the light or dark theme for
+    /// `Theme::System` depending on the preference of the desktop
+    pub fn definition(&self) -> &'static ThemeDefinition {
+        let index = match self {
+            Theme::System => {
+                let name = if PREFERS_DARK.load(Ordering::SeqCst) {
+                    "Dark"
+                } else {
+                    "Light"
+                };
+
+                definitions()
+                    .iter()
+                    .position(|d| d.name == name)
+                    .unwrap_or_default()
+            }
+            Theme::Defined(index) => *index,
+        };
+
+        &definitions()[index]
+    }
+
+    /// Get the font sizes of the theme
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let title_size: u16 = theme.font_sizes().title;
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `FontSizes` of the theme
+    pub fn font_sizes(&self) -> FontSizes {
+        self.definition().font_sizes
+    }
+
+    /// Get the colors of the lines that were added, removed or changed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let added: Color = theme.diff_colors().added.0;
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `DiffColors` of the theme
+    pub fn diff_colors(&self) -> DiffColors {
+        self.definition().diff
+    }
+
+    /// Get the color of regular text
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let color: Color = theme.text_color();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The text `Color` of the palette
+    pub fn text_color(&self) -> Color {
+        self.definition().palette.text.0
+    }
+
+    /// Get the color that should be used to display a token of the given kind
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The `TokenKind` of the token
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let color = theme.syntax_color(TokenKind::Keyword);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Color` for the given `TokenKind`
+    pub fn syntax_color(&self, kind: TokenKind) -> Color {
+        let syntax = &self.definition().syntax;
+        match kind {
+            TokenKind::Keyword => syntax.keyword.0,
+            TokenKind::Number => syntax.number.0,
+            TokenKind::String => syntax.string.0,
+            TokenKind::Comment => syntax.comment.0,
+            TokenKind::Punctuation => syntax.punctuation.0,
+            _ => self.text_color(),
+        }
+    }
+
+    fn palette(&self) -> themed::Palette {
+        let palette = &self.definition().palette;
+        themed::Palette {
+            background: palette.background.0,
+            text: palette.text.0,
+            surface: palette.surface.0,
+            accent: palette.accent.0,
+            active: palette.active.0,
+            hovered: palette.hovered.0,
+            active_text: palette.active_text.0,
+            placeholder: palette.placeholder.0,
+        }
+    }
+}
+
+impl std::fmt::Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Theme::System => write!(f, "System"),
+            Theme::Defined(_) => write!(f, "{}", self.definition().name),
+        }
+    }
 }
 
-impl Default for Theme {
-    fn default() -> Theme {
-        Theme::Dark
+impl Serialize for Theme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Theme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(Theme::by_name(&name).unwrap_or_default())
     }
 }
 
 impl<'a> From<Theme> for Box<dyn container::StyleSheet + 'a> {
     fn from(theme: Theme) -> Self {
-        match theme {
-            Theme::Light => Default::default(),
-            Theme::Dark => dark::Container.into(),
-        }
+        themed::Container(theme.palette()).into()
     }
 }
 
 impl<'a> From<Theme> for Box<dyn radio::StyleSheet + 'a> {
     fn from(theme: Theme) -> Self {
-        match theme {
-            Theme::Light => Default::default(),
-            Theme::Dark => dark::Radio.into(),
-        }
+        themed::Radio(theme.palette()).into()
     }
 }
 
 impl<'a> From<Theme> for Box<dyn text_input::StyleSheet + 'a> {
     fn from(theme: Theme) -> Self {
-        match theme {
-            Theme::Light => Default::default(),
-            Theme::Dark => dark::TextInput.into(),
-        }
+        themed::TextInput(theme.palette()).into()
+    }
+}
+
+impl<'a> From<Theme> for Box<dyn pick_list::StyleSheet + 'a> {
+    fn from(theme: Theme) -> Self {
+        themed::PickList(theme.palette()).into()
     }
 }
 
 impl<'a> From<Theme> for Box<dyn button::StyleSheet + 'a> {
     fn from(theme: Theme) -> Self {
-        match theme {
-            Theme::Light => light::Button.into(),
-            Theme::Dark => dark::Button.into(),
-        }
+        themed::Button(theme.palette()).into()
     }
 }
 
 impl<'a> From<Theme> for Box<dyn scrollable::StyleSheet + 'a> {
     fn from(theme: Theme) -> Self {
-        match theme {
-            Theme::Light => Default::default(),
-            Theme::Dark => dark::Scrollable.into(),
-        }
+        themed::Scrollable(theme.palette()).into()
     }
 }
 
 impl<'a> From<Theme> for Box<dyn slider::StyleSheet + 'a> {
     fn from(theme: Theme) -> Self {
-        match theme {
-            Theme::Light => Default::default(),
-            Theme::Dark => dark::Slider.into(),
-        }
+        themed::Slider(theme.palette()).into()
     }
 }
 
 impl From<Theme> for Box<dyn progress_bar::StyleSheet> {
     fn from(theme: Theme) -> Self {
-        match theme {
-            Theme::Light => Default::default(),
-            Theme::Dark => dark::ProgressBar.into(),
-        }
+        themed::ProgressBar(theme.palette()).into()
     }
 }
 
 impl<'a> From<Theme> for Box<dyn checkbox::StyleSheet + 'a> {
     fn from(theme: Theme) -> Self {
-        match theme {
-            Theme::Light => Default::default(),
-            Theme::Dark => dark::Checkbox.into(),
-        }
+        themed::Checkbox(theme.palette()).into()
     }
 }
 
 impl From<Theme> for Box<dyn toggler::StyleSheet> {
     fn from(theme: Theme) -> Self {
-        match theme {
-            Theme::Light => Default::default(),
-            Theme::Dark => dark::Toggler.into(),
-        }
+        themed::Toggler(theme.palette()).into()
     }
 }
 
 impl From<Theme> for Box<dyn rule::StyleSheet> {
     fn from(theme: Theme) -> Self {
-        match theme {
-            Theme::Light => Default::default(),
-            Theme::Dark => dark::Rule.into(),
+        themed::Rule(theme.palette()).into()
+    }
+}
+
+/// The style of a notification, which is colored by its `Severity` in every `Theme`
+pub struct Notification(pub Severity);
+
+impl container::StyleSheet for Notification {
+    fn style(&self) -> container::Style {
+        let background = match self.0 {
+            Severity::Info => Color::from_rgb8(0x1E, 0x6B, 0xB8),
+            Severity::Warning => Color::from_rgb8(0xB3, 0x6B, 0x00),
+            Severity::Error => Color::from_rgb8(0xB0, 0x2A, 0x2A),
+        };
+
+        container::Style {
+            background: background.into(),
+            text_color: Color::WHITE.into(),
+            border_radius: 3.0,
+            ..container::Style::default()
         }
     }
 }
 
-mod light {
-    use iced::{button, Color, Vector};
+/// The style of text that matches a search, which uses the accent color of a `Theme`
+pub struct Highlight(pub Theme);
 
-    pub struct Button;
+impl container::StyleSheet for Highlight {
+    fn style(&self) -> container::Style {
+        let palette = self.0.palette();
+        container::Style {
+            background: palette.accent.into(),
+            text_color: palette.background.into(),
+            border_radius: 2.0,
+            ..container::Style::default()
+        }
+    }
+}
 
-    impl button::StyleSheet for Button {
-        fn active(&self) -> button::Style {
+/// The style of a segment of the overview ruler, which is colored like the change it contains
+pub struct OverviewMark(pub Theme, pub Option<Color>);
+
+impl button::StyleSheet for OverviewMark {
+    fn active(&self) -> button::Style {
+        let palette = self.0.palette();
+        button::Style {
+            background: self.1.unwrap_or(palette.surface).into(),
+            ..button::Style::default()
+        }
+    }
+
+    fn hovered(&self) -> button::Style {
+        let palette = self.0.palette();
+        button::Style {
+            background: self.1.unwrap_or(palette.hovered).into(),
+            border_width: 1.0,
+            border_color: palette.accent,
+            ..button::Style::default()
+        }
+    }
+}
+
+/// The style of a line number in the gutter of the side-by-side view
+pub struct Gutter(pub Theme, pub bool);
+
+impl button::StyleSheet for Gutter {
+    fn active(&self) -> button::Style {
+        let palette = self.0.palette();
+        if self.1 {
             button::Style {
-                background: Color::from_rgb(0.11, 0.42, 0.87).into(),
-                border_radius: 3.0,
-                shadow_offset: Vector::new(1.0, 1.0),
-                text_color: Color::from_rgb8(0xEE, 0xEE, 0xEE),
+                background: palette.accent.into(),
+                border_radius: 2.0,
+                text_color: palette.background,
                 ..button::Style::default()
             }
-        }
-
-        fn hovered(&self) -> button::Style {
+        } else {
             button::Style {
-                text_color: Color::WHITE,
-                shadow_offset: Vector::new(1.0, 2.0),
-                ..self.active()
+                background: None,
+                text_color: palette.placeholder,
+                ..button::Style::default()
             }
         }
     }
-}
 
-mod dark {
-    use iced::{
-        button, checkbox, container, progress_bar, radio, rule, scrollable, slider, text_input,
-        toggler, Color,
-    };
+    fn hovered(&self) -> button::Style {
+        let palette = self.0.palette();
+        button::Style {
+            text_color: if self.1 {
+                palette.background
+            } else {
+                palette.text
+            },
+            ..self.active()
+        }
+    }
+}
 
-    const SURFACE: Color = Color::from_rgb(
-        0x40 as f32 / 255.0,
-        0x44 as f32 / 255.0,
-        0x4B as f32 / 255.0,
-    );
+/// The style of the button of a tab, which looks active when the tab is selected
+pub struct Tab(pub Theme, pub bool);
+
+impl button::StyleSheet for Tab {
+    fn active(&self) -> button::Style {
+        let palette = self.0.palette();
+        button::Style {
+            background: if self.1 {
+                palette.active.into()
+            } else {
+                palette.surface.into()
+            },
+            border_radius: 3.0,
+            text_color: if self.1 {
+                palette.active_text
+            } else {
+                palette.text
+            },
+            ..button::Style::default()
+        }
+    }
 
-    const ACCENT: Color = Color::from_rgb(
-        0x6F as f32 / 255.0,
-        0xFF as f32 / 255.0,
-        0xE9 as f32 / 255.0,
-    );
+    fn hovered(&self) -> button::Style {
+        let palette = self.0.palette();
+        button::Style {
+            background: palette.hovered.into(),
+            text_color: palette.active_text,
+            ..self.active()
+        }
+    }
+}
 
-    const ACTIVE: Color = Color::from_rgb(
-        0x72 as f32 / 255.0,
-        0x89 as f32 / 255.0,
-        0xDA as f32 / 255.0,
-    );
+mod themed {
+    use iced::{
+        button, checkbox, container, pick_list, progress_bar, radio, rule, scrollable, slider,
+        text_input, toggler, Color,
+    };
 
-    const HOVERED: Color = Color::from_rgb(
-        0x67 as f32 / 255.0,
-        0x7B as f32 / 255.0,
-        0xC4 as f32 / 255.0,
-    );
+    #[derive(Debug, Clone, Copy)]
+    pub struct Palette {
+        pub background: Color,
+        pub text: Color,
+        pub surface: Color,
+        pub accent: Color,
+        pub active: Color,
+        pub hovered: Color,
+        pub active_text: Color,
+        pub placeholder: Color,
+    }
 
-    pub struct Container;
+    pub struct Container(pub Palette);
 
     impl container::StyleSheet for Container {
         fn style(&self) -> container::Style {
             container::Style {
-                background: Color::from_rgb8(0x36, 0x39, 0x3F).into(),
-                text_color: Color::WHITE.into(),
+                background: self.0.background.into(),
+                text_color: self.0.text.into(),
                 ..container::Style::default()
             }
         }
     }
 
-    pub struct Radio;
+    pub struct Radio(pub Palette);
 
     impl radio::StyleSheet for Radio {
         fn active(&self) -> radio::Style {
             radio::Style {
-                background: SURFACE.into(),
-                dot_color: ACTIVE,
+                background: self.0.surface.into(),
+                dot_color: self.0.active,
                 border_width: 1.0,
-                border_color: ACTIVE,
+                border_color: self.0.active,
                 text_color: None,
             }
         }
 
         fn hovered(&self) -> radio::Style {
             radio::Style {
-                background: Color { a: 0.5, ..SURFACE }.into(),
+                background: Color {
+                    a: 0.5,
+                    ..self.0.surface
+                }
+                .into(),
                 ..self.active()
             }
         }
     }
 
-    pub struct TextInput;
+    pub struct TextInput(pub Palette);
 
     impl text_input::StyleSheet for TextInput {
         fn active(&self) -> text_input::Style {
             text_input::Style {
-                background: SURFACE.into(),
+                background: self.0.surface.into(),
                 border_radius: 2.0,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
@@ -212,7 +704,7 @@ mod dark {
         fn focused(&self) -> text_input::Style {
             text_input::Style {
                 border_width: 1.0,
-                border_color: ACCENT,
+                border_color: self.0.accent,
                 ..self.active()
             }
         }
@@ -220,40 +712,82 @@ mod dark {
         fn hovered(&self) -> text_input::Style {
             text_input::Style {
                 border_width: 1.0,
-                border_color: Color { a: 0.3, ..ACCENT },
+                border_color: Color {
+                    a: 0.3,
+                    ..self.0.accent
+                },
                 ..self.focused()
             }
         }
 
         fn placeholder_color(&self) -> Color {
-            Color::from_rgb(0.4, 0.4, 0.4)
+            self.0.placeholder
         }
 
         fn value_color(&self) -> Color {
-            Color::WHITE
+            self.0.text
         }
 
         fn selection_color(&self) -> Color {
-            ACTIVE
+            Color {
+                a: 0.5,
+                ..self.0.active
+            }
+        }
+    }
+
+    pub struct PickList(pub Palette);
+
+    impl pick_list::StyleSheet for PickList {
+        fn menu(&self) -> pick_list::Menu {
+            pick_list::Menu {
+                text_color: self.0.text,
+                background: self.0.surface.into(),
+                border_width: 1.0,
+                border_color: self.0.active,
+                selected_background: self.0.active.into(),
+                selected_text_color: self.0.active_text,
+            }
+        }
+
+        fn active(&self) -> pick_list::Style {
+            pick_list::Style {
+                text_color: self.0.text,
+                background: self.0.surface.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                border_radius: 2.0,
+                ..pick_list::Style::default()
+            }
+        }
+
+        fn hovered(&self) -> pick_list::Style {
+            pick_list::Style {
+                border_width: 1.0,
+                border_color: Color {
+                    a: 0.3,
+                    ..self.0.accent
+                },
+                ..self.active()
+            }
         }
     }
 
-    pub struct Button;
+    pub struct Button(pub Palette);
 
     impl button::StyleSheet for Button {
         fn active(&self) -> button::Style {
             button::Style {
-                background: ACTIVE.into(),
+                background: self.0.active.into(),
                 border_radius: 3.0,
-                text_color: Color::WHITE,
+                text_color: self.0.active_text,
                 ..button::Style::default()
             }
         }
 
         fn hovered(&self) -> button::Style {
             button::Style {
-                background: HOVERED.into(),
-                text_color: Color::WHITE,
+                background: self.0.hovered.into(),
                 ..self.active()
             }
         }
@@ -261,23 +795,23 @@ mod dark {
         fn pressed(&self) -> button::Style {
             button::Style {
                 border_width: 1.0,
-                border_color: Color::WHITE,
+                border_color: self.0.active_text,
                 ..self.hovered()
             }
         }
     }
 
-    pub struct Scrollable;
+    pub struct Scrollable(pub Palette);
 
     impl scrollable::StyleSheet for Scrollable {
         fn active(&self) -> scrollable::Scrollbar {
             scrollable::Scrollbar {
-                background: SURFACE.into(),
+                background: self.0.surface.into(),
                 border_radius: 2.0,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
                 scroller: scrollable::Scroller {
-                    color: ACTIVE,
+                    color: self.0.active,
                     border_radius: 2.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
@@ -289,9 +823,13 @@ mod dark {
             let active = self.active();
 
             scrollable::Scrollbar {
-                background: Color { a: 0.5, ..SURFACE }.into(),
+                background: Color {
+                    a: 0.5,
+                    ..self.0.surface
+                }
+                .into(),
                 scroller: scrollable::Scroller {
-                    color: HOVERED,
+                    color: self.0.hovered,
                     ..active.scroller
                 },
                 ..active
@@ -303,7 +841,7 @@ mod dark {
 
             scrollable::Scrollbar {
                 scroller: scrollable::Scroller {
-                    color: Color::from_rgb(0.85, 0.85, 0.85),
+                    color: self.0.accent,
                     ..hovered.scroller
                 },
                 ..hovered
@@ -311,15 +849,21 @@ mod dark {
         }
     }
 
-    pub struct Slider;
+    pub struct Slider(pub Palette);
 
     impl slider::StyleSheet for Slider {
         fn active(&self) -> slider::Style {
             slider::Style {
-                rail_colors: (ACTIVE, Color { a: 0.1, ..ACTIVE }),
+                rail_colors: (
+                    self.0.active,
+                    Color {
+                        a: 0.1,
+                        ..self.0.active
+                    },
+                ),
                 handle: slider::Handle {
                     shape: slider::HandleShape::Circle { radius: 9.0 },
-                    color: ACTIVE,
+                    color: self.0.active,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
                 },
@@ -331,7 +875,7 @@ mod dark {
 
             slider::Style {
                 handle: slider::Handle {
-                    color: HOVERED,
+                    color: self.0.hovered,
                     ..active.handle
                 },
                 ..active
@@ -343,7 +887,7 @@ mod dark {
 
             slider::Style {
                 handle: slider::Handle {
-                    color: Color::from_rgb(0.85, 0.85, 0.85),
+                    color: self.0.accent,
                     ..active.handle
                 },
                 ..active
@@ -351,28 +895,33 @@ mod dark {
         }
     }
 
-    pub struct ProgressBar;
+    pub struct ProgressBar(pub Palette);
 
     impl progress_bar::StyleSheet for ProgressBar {
         fn style(&self) -> progress_bar::Style {
             progress_bar::Style {
-                background: SURFACE.into(),
-                bar: ACTIVE.into(),
+                background: self.0.surface.into(),
+                bar: self.0.active.into(),
                 border_radius: 10.0,
             }
         }
     }
 
-    pub struct Checkbox;
+    pub struct Checkbox(pub Palette);
 
     impl checkbox::StyleSheet for Checkbox {
         fn active(&self, is_checked: bool) -> checkbox::Style {
             checkbox::Style {
-                background: if is_checked { ACTIVE } else { SURFACE }.into(),
-                checkmark_color: Color::WHITE,
+                background: if is_checked {
+                    self.0.active
+                } else {
+                    self.0.surface
+                }
+                .into(),
+                checkmark_color: self.0.active_text,
                 border_radius: 2.0,
                 border_width: 1.0,
-                border_color: ACTIVE,
+                border_color: self.0.active,
                 text_color: None,
             }
         }
@@ -381,7 +930,11 @@ mod dark {
             checkbox::Style {
                 background: Color {
                     a: 0.8,
-                    ..if is_checked { ACTIVE } else { SURFACE }
+                    ..if is_checked {
+                        self.0.active
+                    } else {
+                        self.0.surface
+                    }
                 }
                 .into(),
                 ..self.active(is_checked)
@@ -389,41 +942,45 @@ mod dark {
         }
     }
 
-    pub struct Toggler;
+    pub struct Toggler(pub Palette);
 
     impl toggler::StyleSheet for Toggler {
         fn active(&self, is_active: bool) -> toggler::Style {
             toggler::Style {
-                background: if is_active { ACTIVE } else { SURFACE },
+                background: if is_active {
+                    self.0.active
+                } else {
+                    self.0.surface
+                },
                 background_border: None,
-                foreground: if is_active { Color::WHITE } else { ACTIVE },
+                foreground: if is_active {
+                    self.0.active_text
+                } else {
+                    self.0.active
+                },
                 foreground_border: None,
             }
         }
 
         fn hovered(&self, is_active: bool) -> toggler::Style {
+            let active = self.active(is_active);
+
             toggler::Style {
-                background: if is_active { ACTIVE } else { SURFACE },
-                background_border: None,
-                foreground: if is_active {
-                    Color {
-                        a: 0.5,
-                        ..Color::WHITE
-                    }
-                } else {
-                    Color { a: 0.5, ..ACTIVE }
+                foreground: Color {
+                    a: 0.5,
+                    ..active.foreground
                 },
-                foreground_border: None,
+                ..active
             }
         }
     }
 
-    pub struct Rule;
+    pub struct Rule(pub Palette);
 
     impl rule::StyleSheet for Rule {
         fn style(&self) -> rule::Style {
             rule::Style {
-                color: SURFACE,
+                color: self.0.surface,
                 width: 2,
                 radius: 1.0,
                 fill_mode: rule::FillMode::Padded(15),
@@ -0,0 +1,763 @@
+use std::path::Path;
+
+/// A programming language that is recognized by the `Lexer`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Language {
+    #[default]
+    PlainText,
+    Rust,
+    C,
+    Cpp,
+    CSharp,
+    Java,
+    JavaScript,
+    TypeScript,
+    Go,
+    Python,
+    Json,
+    Sql,
+}
+
+/// The kind of a `Token`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TokenKind {
+    Keyword,
+    Identifier,
+    Number,
+    String,
+    Comment,
+    Punctuation,
+    Whitespace,
+}
+
+/// A token of source code and the zero-based line on which it starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    pub line: usize,
+}
+
+struct LanguageRules {
+    line_comment: Option<&'static str>,
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    keywords: &'static [&'static str],
+    case_sensitive: bool,
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+const C_KEYWORDS: &[&str] = &[
+    "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else",
+    "enum", "extern", "float", "for", "goto", "if", "inline", "int", "long", "register",
+    "restrict", "return", "short", "signed", "sizeof", "static", "struct", "switch", "typedef",
+    "union", "unsigned", "void", "volatile", "while", "NULL",
+];
+
+const CPP_KEYWORDS: &[&str] = &[
+    "auto",
+    "bool",
+    "break",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "constexpr",
+    "continue",
+    "default",
+    "delete",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "explicit",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "friend",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "namespace",
+    "new",
+    "noexcept",
+    "nullptr",
+    "operator",
+    "override",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "struct",
+    "switch",
+    "template",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typedef",
+    "typename",
+    "union",
+    "unsigned",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "async",
+    "await",
+    "base",
+    "bool",
+    "break",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "false",
+    "finally",
+    "float",
+    "for",
+    "foreach",
+    "if",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "out",
+    "override",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sealed",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "using",
+    "var",
+    "virtual",
+    "void",
+    "while",
+];
+
+const JAVA_KEYWORDS: &[&str] = &[
+    "abstract",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "var",
+    "void",
+    "volatile",
+    "while",
+];
+
+const JAVASCRIPT_KEYWORDS: &[&str] = &[
+    "async",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "let",
+    "new",
+    "null",
+    "of",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "undefined",
+    "var",
+    "void",
+    "while",
+    "yield",
+];
+
+const TYPESCRIPT_KEYWORDS: &[&str] = &[
+    "abstract",
+    "any",
+    "as",
+    "async",
+    "await",
+    "boolean",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "declare",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "keyof",
+    "let",
+    "namespace",
+    "never",
+    "new",
+    "null",
+    "number",
+    "of",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "return",
+    "static",
+    "string",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "undefined",
+    "unknown",
+    "var",
+    "void",
+    "while",
+    "yield",
+];
+
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "false",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "nil",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "true",
+    "type",
+    "var",
+];
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+const JSON_KEYWORDS: &[&str] = &["true", "false", "null"];
+
+const SQL_KEYWORDS: &[&str] = &[
+    "add",
+    "all",
+    "alter",
+    "and",
+    "as",
+    "asc",
+    "begin",
+    "between",
+    "by",
+    "case",
+    "commit",
+    "create",
+    "delete",
+    "desc",
+    "distinct",
+    "drop",
+    "else",
+    "end",
+    "exists",
+    "from",
+    "group",
+    "having",
+    "in",
+    "index",
+    "inner",
+    "insert",
+    "into",
+    "is",
+    "join",
+    "key",
+    "left",
+    "like",
+    "limit",
+    "not",
+    "null",
+    "on",
+    "or",
+    "order",
+    "outer",
+    "primary",
+    "references",
+    "right",
+    "rollback",
+    "select",
+    "set",
+    "table",
+    "then",
+    "union",
+    "unique",
+    "update",
+    "values",
+    "view",
+    "when",
+    "where",
+    "with",
+];
+
+impl Language {
+    pub const ALL: [Language; 12] = [
+        Language::PlainText,
+        Language::Rust,
+        Language::C,
+        Language::Cpp,
+        Language::CSharp,
+        Language::Java,
+        Language::JavaScript,
+        Language::TypeScript,
+        Language::Go,
+        Language::Python,
+        Language::Json,
+        Language::Sql,
+    ];
+
+    /// Detect the `Language` of a file by its extension
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::syntax::Language;
+    /// let language = Language::from_path("/path/to/file.rs");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The detected `Language` or `Language::PlainText` if the extension is unknown
+    pub fn from_path(path: &str) -> Language {
+        let extension = match Path::new(path).extension().and_then(|e| e.to_str()) {
+            Some(e) => e.to_lowercase(),
+            None => return Language::PlainText,
+        };
+
+        match extension.as_str() {
+            "rs" => Language::Rust,
+            "c" | "h" => Language::C,
+            "cpp" | "cc" | "cxx" | "hpp" | "hh" | "hxx" => Language::Cpp,
+            "cs" => Language::CSharp,
+            "java" => Language::Java,
+            "js" | "mjs" | "cjs" | "jsx" => Language::JavaScript,
+            "ts" | "tsx" => Language::TypeScript,
+            "go" => Language::Go,
+            "py" | "pyw" => Language::Python,
+            "json" => Language::Json,
+            "sql" => Language::Sql,
+            _ => Language::PlainText,
+        }
+    }
+
+    /// Check whether the keywords of the `Language` are case sensitive
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::syntax::Language;
+    /// let case_sensitive = Language::Sql.has_case_sensitive_keywords();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if the keywords are case sensitive, otherwise `false`
+    pub fn has_case_sensitive_keywords(&self) -> bool {
+        self.rules().case_sensitive
+    }
+
+    fn rules(&self) -> LanguageRules {
+        let c_like = |keywords| LanguageRules {
+            line_comment: Some("//"),
+            block_comment: Some(("/*", "*/")),
+            quotes: &['"', '\''],
+            keywords,
+            case_sensitive: true,
+        };
+
+        match self {
+            Language::PlainText => LanguageRules {
+                line_comment: None,
+                block_comment: None,
+                quotes: &[],
+                keywords: &[],
+                case_sensitive: true,
+            },
+            Language::Rust => c_like(RUST_KEYWORDS),
+            Language::C => c_like(C_KEYWORDS),
+            Language::Cpp => c_like(CPP_KEYWORDS),
+            Language::CSharp => c_like(CSHARP_KEYWORDS),
+            Language::Java => c_like(JAVA_KEYWORDS),
+            Language::JavaScript => LanguageRules {
+                quotes: &['"', '\'', '`'],
+                ..c_like(JAVASCRIPT_KEYWORDS)
+            },
+            Language::TypeScript => LanguageRules {
+                quotes: &['"', '\'', '`'],
+                ..c_like(TYPESCRIPT_KEYWORDS)
+            },
+            Language::Go => LanguageRules {
+                quotes: &['"', '\'', '`'],
+                ..c_like(GO_KEYWORDS)
+            },
+            Language::Python => LanguageRules {
+                line_comment: Some("#"),
+                block_comment: None,
+                quotes: &['"', '\''],
+                keywords: PYTHON_KEYWORDS,
+                case_sensitive: true,
+            },
+            Language::Json => LanguageRules {
+                line_comment: None,
+                block_comment: None,
+                quotes: &['"'],
+                keywords: JSON_KEYWORDS,
+                case_sensitive: true,
+            },
+            Language::Sql => LanguageRules {
+                line_comment: Some("--"),
+                block_comment: Some(("/*", "*/")),
+                quotes: &['\'', '"'],
+                keywords: SQL_KEYWORDS,
+                case_sensitive: false,
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Language::PlainText => "Plain text",
+            Language::Rust => "Rust",
+            Language::C => "C",
+            Language::Cpp => "C++",
+            Language::CSharp => "C#",
+            Language::Java => "Java",
+            Language::JavaScript => "JavaScript",
+            Language::TypeScript => "TypeScript",
+            Language::Go => "Go",
+            Language::Python => "Python",
+            Language::Json => "JSON",
+            Language::Sql => "SQL",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Splits source code into tokens
+#[derive(Debug, Clone)]
+pub struct Lexer {
+    pub language: Language,
+}
+
+impl Lexer {
+    /// Initialize a new `Lexer`
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The `Language` that should be used to split text into tokens
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::syntax::{Language, Lexer};
+    /// let lexer = Lexer::new(Language::Rust);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `Lexer` instance
+    pub fn new(language: Language) -> Lexer {
+        Lexer { language }
+    }
+
+    /// Split a text into tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text that should be split into tokens
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::syntax::{Language, Lexer, Token};
+    /// # let lexer = Lexer::new(Language::Rust);
+    /// let tokens: Vec<Token> = lexer.tokenize("fn main() {}");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` that contains all the tokens in the text, including whitespace and comments
+    pub fn tokenize(&self, text: &str) -> Vec<Token> {
+        let rules = self.language.rules();
+        let chars: Vec<char> = text.chars().collect();
+
+        let mut tokens = vec![];
+        let mut line = 0;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let start = i;
+
+            let kind = if c.is_whitespace() {
+                while i < chars.len() && chars[i].is_whitespace() {
+                    i += 1;
+                }
+                TokenKind::Whitespace
+            } else if rules
+                .line_comment
+                .is_some_and(|p| starts_with(&chars, i, p))
+            {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                TokenKind::Comment
+            } else if let Some((open, close)) = rules
+                .block_comment
+                .filter(|(open, _)| starts_with(&chars, i, open))
+            {
+                i += open.chars().count();
+                while i < chars.len() && !starts_with(&chars, i, close) {
+                    i += 1;
+                }
+                i = usize::min(i + close.chars().count(), chars.len());
+                TokenKind::Comment
+            } else if self.language == Language::Rust && c == '\'' && !is_char_literal(&chars, i) {
+                // A lifetime or loop label rather than a character literal
+                i += 1;
+                while i < chars.len() && is_identifier_char(chars[i]) {
+                    i += 1;
+                }
+                TokenKind::Identifier
+            } else if rules.quotes.contains(&c) {
+                i = end_of_string(&chars, i, self.language == Language::Python);
+                TokenKind::String
+            } else if c.is_ascii_digit() {
+                while i < chars.len() && (is_identifier_char(chars[i]) || chars[i] == '.') {
+                    i += 1;
+                }
+                TokenKind::Number
+            } else if is_identifier_char(c) {
+                while i < chars.len() && is_identifier_char(chars[i]) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let is_keyword = if rules.case_sensitive {
+                    rules.keywords.contains(&word.as_str())
+                } else {
+                    rules.keywords.contains(&word.to_lowercase().as_str())
+                };
+
+                if is_keyword {
+                    TokenKind::Keyword
+                } else {
+                    TokenKind::Identifier
+                }
+            } else {
+                i += 1;
+                TokenKind::Punctuation
+            };
+
+            let text: String = chars[start..i].iter().collect();
+            let newlines = text.matches('\n').count();
+            tokens.push(Token { kind, text, line });
+            line += newlines;
+        }
+
+        tokens
+    }
+}
+
+/// Check whether the given pattern occurs at the given position
+fn starts_with(chars: &[char], index: usize, pattern: &str) -> bool {
+    pattern
+        .chars()
+        .enumerate()
+        .all(|(i, p)| chars.get(index + i) == Some(&p))
+}
+
+/// Check whether a character can be part of an identifier
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Check whether the quote at the given position starts a Rust character literal
+fn is_char_literal(chars: &[char], index: usize) -> bool {
+    match chars.get(index + 1) {
+        Some('\\') => true,
+        Some(_) => chars.get(index + 2) == Some(&'\''),
+        None => false,
+    }
+}
+
+/// Find the end of the string literal that starts at the given position
+fn end_of_string(chars: &[char], index: usize, allow_triple_quotes: bool) -> usize {
+    let quote = chars[index];
+    let triple: String = [quote; 3].iter().collect();
+
+    if allow_triple_quotes && starts_with(chars, index, &triple) {
+        let mut i = index + 3;
+        while i < chars.len() && !starts_with(chars, i, &triple) {
+            i += 1;
+        }
+        return usize::min(i + 3, chars.len());
+    }
+
+    let mut i = index + 1;
+    while i < chars.len() {
+        if chars[i] == '\\' {
+            i += 2;
+            continue;
+        }
+        if chars[i] == quote {
+            return i + 1;
+        }
+        i += 1;
+    }
+
+    chars.len()
+}
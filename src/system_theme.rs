@@ -0,0 +1,189 @@
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use iced::futures::channel::mpsc;
+use iced::futures::stream::BoxStream;
+use iced::Subscription;
+
+/// The interval at which the preference is checked when changes cannot be monitored
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Detect whether the desktop prefers a dark color scheme
+///
+/// On Linux the freedesktop portal setting is used, with the GTK theme name as a fallback.
+///
+/// # Example
+///
+/// ```rust
+/// let prefers_dark: Option<bool> = prefers_dark();
+/// ```
+///
+/// # Returns
+///
+/// `Some(true)` for a dark preference, `Some(false)` for a light preference or `None` if the
+/// preference could not be detected
+pub fn prefers_dark() -> Option<bool> {
+    if cfg!(target_os = "macos") {
+        // The key only exists when the dark appearance is selected
+        let output = Command::new("defaults")
+            .args(["read", "-g", "AppleInterfaceStyle"])
+            .output()
+            .ok()?;
+        return Some(String::from_utf8_lossy(&output.stdout).trim() == "Dark");
+    }
+
+    if cfg!(windows) {
+        let output = run(
+            "reg",
+            &[
+                "query",
+                "HKCU\\Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+                "/v",
+                "AppsUseLightTheme",
+            ],
+        )?;
+        return Some(output.contains("0x0"));
+    }
+
+    portal_color_scheme().or_else(gtk_theme)
+}
+
+/// Read the `color-scheme` setting of the freedesktop portal
+fn portal_color_scheme() -> Option<bool> {
+    let output = run(
+        "gdbus",
+        &[
+            "call",
+            "--session",
+            "--dest",
+            "org.freedesktop.portal.Desktop",
+            "--object-path",
+            "/org/freedesktop/portal/desktop",
+            "--method",
+            "org.freedesktop.portal.Settings.Read",
+            "org.freedesktop.appearance",
+            "color-scheme",
+        ],
+    )?;
+
+    // The output looks like `(<<uint32 1>>,)`, where 1 prefers dark, 2 prefers light and 0 has no
+    // preference
+    match output.split("uint32").nth(1)?.trim_start().chars().next()? {
+        '1' => Some(true),
+        '2' => Some(false),
+        _ => None,
+    }
+}
+
+/// Check whether the name of the GTK theme refers to a dark variant
+fn gtk_theme() -> Option<bool> {
+    let name = match std::env::var("GTK_THEME") {
+        Ok(d) if !d.is_empty() => d,
+        _ => run(
+            "gsettings",
+            &["get", "org.gnome.desktop.interface", "gtk-theme"],
+        )?,
+    };
+
+    Some(name.to_lowercase().contains("dark"))
+}
+
+/// Run a command and get its standard output
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Watch the desktop for changes of the color scheme preference
+///
+/// # Example
+///
+/// ```rust
+/// let subscription: Subscription<bool> = subscription();
+/// ```
+///
+/// # Returns
+///
+/// The `Subscription` that produces `true` when the desktop switches to a dark color scheme and
+/// `false` when it switches to a light color scheme
+pub fn subscription() -> Subscription<bool> {
+    Subscription::from_recipe(Watcher)
+}
+
+/// Produces the color scheme preference whenever it changes
+struct Watcher;
+
+impl<H: Hasher, I> iced_native::subscription::Recipe<H, I> for Watcher {
+    type Output = bool;
+
+    fn hash(&self, state: &mut H) {
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<'static, I>) -> BoxStream<'static, bool> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        std::thread::spawn(move || {
+            let mut current = prefers_dark();
+            // Send the preference when it changed and stop once the subscription is dropped
+            let mut check = move || {
+                let preference = prefers_dark();
+                if preference == current {
+                    return true;
+                }
+
+                current = preference;
+                match preference {
+                    Some(d) => sender.unbounded_send(d).is_ok(),
+                    None => true,
+                }
+            };
+
+            if cfg!(target_os = "linux") {
+                let child = Command::new("gdbus")
+                    .args([
+                        "monitor",
+                        "--session",
+                        "--dest",
+                        "org.freedesktop.portal.Desktop",
+                        "--object-path",
+                        "/org/freedesktop/portal/desktop",
+                    ])
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::null())
+                    .spawn();
+
+                if let Ok(mut child) = child {
+                    if let Some(stdout) = child.stdout.take() {
+                        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                            if line.contains("SettingChanged") && !check() {
+                                let _ = child.kill();
+                                let _ = child.wait();
+                                return;
+                            }
+                        }
+                    }
+
+                    // The monitor exited, so the preference is polled instead
+                    let _ = child.kill();
+                    let _ = child.wait();
+                }
+            }
+
+            loop {
+                std::thread::sleep(POLL_INTERVAL);
+                if !check() {
+                    return;
+                }
+            }
+        });
+
+        Box::pin(receiver)
+    }
+}
@@ -0,0 +1,584 @@
+use iced::{
+    alignment, button, text_input, Alignment, Button, Column, Length, Row, Text, TextInput,
+};
+
+use crate::style;
+use crate::view::Message;
+use crate::virtual_list::VirtualList;
+use text_diff::diff::{diff, DiffOp};
+use text_diff::file_reader::LineFormat;
+
+/// One of the two sides of a comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    First,
+    Second,
+}
+
+/// The maximum number of edits that can be undone
+const MAX_UNDO: usize = 100;
+
+/// The maximum height of the lines of a text area, in pixels
+const HEIGHT: u16 = 200;
+
+/// The padding of the text input of a line, in pixels
+const PADDING: u16 = 2;
+
+/// An edit that replaced a range of lines, so that it can be undone and redone
+///
+/// Only the lines that were replaced are stored, not the whole text.
+struct Edit {
+    start: usize,
+    removed: Vec<String>,
+    inserted: Vec<String>,
+}
+
+/// A multiline text area that is made of a text input per line
+///
+/// The text area keeps the lines that were last loaded or saved, so that unsaved edits can be
+/// detected, and a history of edits that can be undone and redone. The line endings of the loaded
+/// file are kept, so that saving only writes the edits. Only the text inputs of the visible lines
+/// are laid out.
+pub struct TextArea {
+    lines: Vec<String>,
+    saved: Vec<String>,
+    format: LineFormat,
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+    last_edited: Option<usize>,
+    inputs: Vec<text_input::State>,
+    list: VirtualList,
+    btn_undo: button::State,
+    btn_redo: button::State,
+    btn_save: button::State,
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        TextArea {
+            lines: vec![String::new()],
+            saved: vec![String::new()],
+            format: LineFormat::default(),
+            undo: vec![],
+            redo: vec![],
+            last_edited: None,
+            inputs: vec![text_input::State::default()],
+            list: VirtualList::default(),
+            btn_undo: button::State::default(),
+            btn_redo: button::State::default(),
+            btn_save: button::State::default(),
+        }
+    }
+}
+
+impl TextArea {
+    /// Get the lines of the text area
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let lines: &[String] = text_area.lines();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The lines of the text area, without trailing empty lines
+    pub fn lines(&self) -> &[String] {
+        let len = self
+            .lines
+            .iter()
+            .rposition(|l| !l.is_empty())
+            .map_or(0, |i| i + 1);
+        &self.lines[..len]
+    }
+
+    /// Check whether the text area contains any text
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let is_empty: bool = text_area.is_empty();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if every line is empty
+    pub fn is_empty(&self) -> bool {
+        self.lines().is_empty()
+    }
+
+    /// Get the text of the text area as it is saved, with the line endings of the loaded file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let text: String = text_area.text();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The text of every line, including trailing empty lines
+    pub fn text(&self) -> String {
+        self.format.join(&self.lines)
+    }
+
+    /// Get the size of the text of the text area as it is saved
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let bytes: usize = text_area.byte_len();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes of the text
+    pub fn byte_len(&self) -> usize {
+        self.format.byte_len(&self.lines)
+    }
+
+    /// Replace the contents of the text area with lines that were loaded from a file
+    ///
+    /// The history of edits is cleared and the lines are considered to be saved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// text_area.set_lines(vec![String::from("hello")], LineFormat::default());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The new lines of the text area
+    /// * `format` - The `LineFormat` of the file that the lines were loaded from
+    pub fn set_lines(&mut self, lines: Vec<String>, format: LineFormat) {
+        self.format = format;
+        self.undo = vec![];
+        self.redo = vec![];
+        self.last_edited = None;
+        self.replace_lines(lines);
+        self.mark_saved();
+    }
+
+    /// Check whether the text area contains edits that were not saved
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let is_dirty: bool = text_area.is_dirty();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if the lines differ from the lines that were last loaded or saved
+    pub fn is_dirty(&self) -> bool {
+        self.lines != self.saved
+    }
+
+    /// Consider the current lines to be saved
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// text_area.mark_saved();
+    /// ```
+    pub fn mark_saved(&mut self) {
+        self.saved = self.lines.clone();
+    }
+
+    /// Undo the last edit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let changed: bool = text_area.undo();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if an edit was undone
+    pub fn undo(&mut self) -> bool {
+        let edit = match self.undo.pop() {
+            Some(d) => d,
+            None => return false,
+        };
+
+        let edit = self.splice(edit.start, edit.inserted.len(), edit.removed);
+        self.redo.push(edit);
+        self.last_edited = None;
+        true
+    }
+
+    /// Redo the last edit that was undone
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let changed: bool = text_area.redo();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if an edit was redone
+    pub fn redo(&mut self) -> bool {
+        let edit = match self.redo.pop() {
+            Some(d) => d,
+            None => return false,
+        };
+
+        let edit = self.splice(edit.start, edit.inserted.len(), edit.removed);
+        self.undo.push(edit);
+        self.last_edited = None;
+        true
+    }
+
+    /// Replace a range of lines with other lines
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// text_area.replace_range(2, 4, vec![String::from("hello")]);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The index of the first line that is replaced
+    /// * `end` - The index after the last line that is replaced
+    /// * `lines` - The lines that are inserted instead
+    pub fn replace_range(&mut self, start: usize, end: usize, lines: Vec<String>) {
+        let end = usize::min(end, self.lines.len());
+        let start = usize::min(start, end);
+
+        self.edit(start, end, lines, None);
+    }
+
+    /// Restore the saved version of the edits that touch a range of lines
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let changed: bool = text_area.revert_range(2, 4);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The index of the first line of the range
+    /// * `end` - The index after the last line of the range
+    ///
+    /// # Returns
+    ///
+    /// `true` if any edits were reverted
+    pub fn revert_range(&mut self, start: usize, end: usize) -> bool {
+        let ops = diff(&self.saved, &self.lines);
+
+        // The saved and the current range that cover every edit touching the range
+        let mut range: Option<(usize, usize, usize, usize)> = None;
+        for op in ops {
+            let (saved_index, saved_len, index, len) = match op {
+                DiffOp::Equal { .. } => continue,
+                DiffOp::Delete {
+                    old_index,
+                    old_len,
+                    new_index,
+                } => (old_index, old_len, new_index, 0),
+                DiffOp::Insert {
+                    old_index,
+                    new_index,
+                    new_len,
+                } => (old_index, 0, new_index, new_len),
+            };
+
+            let touches = if start == end || len == 0 {
+                index >= start && index <= end
+            } else {
+                index < end && index + len > start
+            };
+            if !touches {
+                continue;
+            }
+
+            range = Some(match range {
+                Some((a, b, c, d)) => (
+                    usize::min(a, saved_index),
+                    usize::max(b, saved_index + saved_len),
+                    usize::min(c, index),
+                    usize::max(d, index + len),
+                ),
+                None => (saved_index, saved_index + saved_len, index, index + len),
+            });
+        }
+
+        match range {
+            Some((a, b, c, d)) => {
+                let lines = self.saved[a..b].to_vec();
+                self.replace_range(c, d, lines);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replace the lines and the text inputs without touching the history
+    fn replace_lines(&mut self, lines: Vec<String>) {
+        self.lines = if lines.is_empty() {
+            vec![String::new()]
+        } else {
+            lines
+        };
+        self.inputs = self
+            .lines
+            .iter()
+            .map(|_| text_input::State::default())
+            .collect();
+        self.list.reset();
+    }
+
+    /// Replace a range of lines and their text inputs without touching the history
+    ///
+    /// A text area always has a line, so an empty line is inserted instead when every line is
+    /// removed.
+    ///
+    /// # Returns
+    ///
+    /// The `Edit` with the removed and the inserted lines
+    fn splice(&mut self, start: usize, len: usize, mut lines: Vec<String>) -> Edit {
+        if start == 0 && len == self.lines.len() && lines.is_empty() {
+            lines.push(String::new());
+        }
+
+        let count = lines.len();
+        let removed: Vec<String> = self.lines.splice(start..start + len, lines).collect();
+        self.inputs.splice(
+            start..start + len,
+            (0..count).map(|_| text_input::State::default()),
+        );
+
+        Edit {
+            start,
+            removed,
+            inserted: self.lines[start..start + count].to_vec(),
+        }
+    }
+
+    /// Replace a range of lines and store the edit in the history
+    ///
+    /// Consecutive edits of the same line are undone at once, like typing a word.
+    fn edit(&mut self, start: usize, end: usize, lines: Vec<String>, line: Option<usize>) {
+        self.redo = vec![];
+
+        let is_same_line = line.is_some() && line == self.last_edited;
+        self.last_edited = line;
+        if let Some(last) = self.undo.last_mut().filter(|_| is_same_line) {
+            // Undoing the edit still restores the line as it was before the first edit
+            self.lines.splice(start..end, lines);
+            last.inserted = self.lines[start..end].to_vec();
+            return;
+        }
+
+        let edit = self.splice(start, end - start, lines);
+        self.undo.push(edit);
+        if self.undo.len() > MAX_UNDO {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Apply a change of the text input of a line
+    ///
+    /// Text that contains line breaks, such as pasted text, is split into multiple lines. An empty
+    /// line is removed when it is changed without a difference in value, which happens when
+    /// backspace or delete is pressed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// text_area.change_line(0, String::from("hello"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the line
+    /// * `value` - The new value of the text input
+    pub fn change_line(&mut self, index: usize, value: String) {
+        let line = match self.lines.get(index) {
+            Some(d) => d,
+            None => return,
+        };
+
+        if line.is_empty() && value.is_empty() {
+            if self.lines.len() > 1 {
+                self.edit(index, index + 1, vec![], None);
+                self.focus(index.saturating_sub(1));
+            }
+            return;
+        }
+
+        if *line == value {
+            return;
+        }
+
+        if !value.contains('\n') {
+            self.edit(index, index + 1, vec![value], Some(index));
+            return;
+        }
+
+        let new_lines: Vec<String> = value
+            .split('\n')
+            .map(|l| String::from(l.strip_suffix('\r').unwrap_or(l)))
+            .collect();
+        let count = new_lines.len();
+
+        self.edit(index, index + 1, new_lines, None);
+        self.focus(index + count - 1);
+    }
+
+    /// Insert an empty line after a line and focus it
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// text_area.insert_line_after(0);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the line after which the empty line is inserted
+    pub fn insert_line_after(&mut self, index: usize) {
+        let index = usize::min(index + 1, self.lines.len());
+        self.edit(index, index, vec![String::new()], None);
+        self.focus(index);
+    }
+
+    /// Apply a change of the scroll position
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// text_area.scrolled(0.5);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The relative scroll position, from `0.0` at the top to `1.0` at the bottom
+    pub fn scrolled(&mut self, offset: f32) {
+        self.list.scrolled(offset);
+    }
+
+    /// Focus the text input of a line, move the cursor to its end and scroll it into view
+    fn focus(&mut self, index: usize) {
+        for input in &mut self.inputs {
+            input.unfocus();
+        }
+
+        if let Some(input) = self.inputs.get_mut(index) {
+            input.focus();
+            input.move_cursor_to_end();
+            self.list.scroll_into_view(index);
+        }
+    }
+
+    /// Create the view of the text area
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let element: Column<'_, Message> = text_area.view(Side::First, "file.txt", theme)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The `Side` of the comparison that the text area belongs to
+    /// * `title` - The title above the text area, which is also the placeholder of the first line
+    /// * `theme` - The `Theme` of the text inputs
+    ///
+    /// # Returns
+    ///
+    /// The `Column` that contains the title, the undo, redo and save buttons and a text input for
+    /// every visible line
+    pub fn view(&mut self, side: Side, title: &str, theme: style::Theme) -> Column<'_, Message> {
+        let size = theme.font_sizes().text;
+        let title = if self.is_dirty() {
+            format!("{} ●", title)
+        } else {
+            String::from(title)
+        };
+
+        let buttons = [
+            (
+                &mut self.btn_undo,
+                "Undo",
+                !self.undo.is_empty(),
+                Message::TextUndoPressed(side),
+            ),
+            (
+                &mut self.btn_redo,
+                "Redo",
+                !self.redo.is_empty(),
+                Message::TextRedoPressed(side),
+            ),
+            (
+                &mut self.btn_save,
+                "Save",
+                true,
+                Message::SaveTextPressed(side),
+            ),
+        ];
+        let header = buttons.into_iter().fold(
+            Row::new()
+                .spacing(5)
+                .align_items(Alignment::Center)
+                .push(Text::new(title.clone()).size(size).width(Length::Fill)),
+            |row, (state, label, enabled, message)| {
+                let mut button = Button::new(
+                    state,
+                    Text::new(label).horizontal_alignment(alignment::Horizontal::Center),
+                )
+                .padding(5)
+                .style(theme);
+                if enabled {
+                    button = button.on_press(message);
+                }
+                row.push(button)
+            },
+        );
+
+        let on_scroll: fn(f32) -> Message = match side {
+            Side::First => |d| Message::TextScrolled(Side::First, d),
+            Side::Second => |d| Message::TextScrolled(Side::Second, d),
+        };
+
+        // The rows refer to the text inputs in the same order as they are stored
+        let lines = &self.lines;
+        let mut inputs = self.inputs.iter_mut();
+        let mut next_input = 0;
+        let scroll = self.list.view(
+            lines.len(),
+            size + 2 * PADDING + 4,
+            HEIGHT,
+            theme,
+            on_scroll,
+            move |index| {
+                let state = match inputs.nth(index - next_input) {
+                    Some(d) => d,
+                    None => return Row::new().into(),
+                };
+                next_input = index + 1;
+
+                let placeholder = if index == 0 { title.as_str() } else { "" };
+                TextInput::new(state, placeholder, &lines[index], move |d| {
+                    Message::TextLineChanged(side, index, d)
+                })
+                .on_submit(Message::TextLineSubmitted(side, index))
+                .padding(PADDING)
+                .size(size)
+                .style(theme)
+                .into()
+            },
+        );
+
+        Column::new()
+            .width(Length::Fill)
+            .spacing(5)
+            .push(header)
+            .push(scroll)
+    }
+}
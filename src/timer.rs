@@ -0,0 +1,54 @@
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use iced::futures::channel::mpsc;
+use iced::futures::stream::BoxStream;
+use iced::Subscription;
+
+/// Produce the current time at a regular interval
+///
+/// A single thread sleeps between the ticks, so that waiting for a deadline does not need a
+/// thread of its own. The thread stops once the subscription is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// let subscription: Subscription<Instant> = every(Duration::from_millis(50));
+/// ```
+///
+/// # Arguments
+///
+/// * `interval` - The time between two ticks
+///
+/// # Returns
+///
+/// The `Subscription` that produces the `Instant` of every tick
+pub fn every(interval: Duration) -> Subscription<Instant> {
+    Subscription::from_recipe(Timer { interval })
+}
+
+struct Timer {
+    interval: Duration,
+}
+
+impl<H: Hasher, I> iced_native::subscription::Recipe<H, I> for Timer {
+    type Output = Instant;
+
+    fn hash(&self, state: &mut H) {
+        std::any::TypeId::of::<Self>().hash(state);
+        self.interval.hash(state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<'static, I>) -> BoxStream<'static, Instant> {
+        let (sender, receiver) = mpsc::unbounded();
+
+        std::thread::spawn(move || loop {
+            std::thread::sleep(self.interval);
+            if sender.unbounded_send(Instant::now()).is_err() {
+                break;
+            }
+        });
+
+        Box::pin(receiver)
+    }
+}
@@ -0,0 +1,349 @@
+use crate::diff::{diff, DiffOp};
+use crate::syntax::{Language, Lexer, Token, TokenKind};
+use crate::vector_comparer::{Difference, IVectorComparer, Origin};
+
+/// Compares the tokens of two files, ignoring comments and formatting
+#[derive(Debug, Clone)]
+pub struct TokenComparer {
+    pub vec1: Vec<String>,
+    pub vec2: Vec<String>,
+    pub language: Language,
+}
+
+impl TokenComparer {
+    /// Set the `Language` that should be used to split the lines into tokens
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The `Language` of both files
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::syntax::Language;
+    /// # use text_diff::token_comparer::TokenComparer;
+    /// # use text_diff::vector_comparer::IVectorComparer;
+    /// # let token_comparer: TokenComparer = IVectorComparer::<String>::new(vec![], vec![]);
+    /// let token_comparer = token_comparer.with_language(Language::Rust);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `TokenComparer` that uses the given `Language`
+    pub fn with_language(mut self, language: Language) -> TokenComparer {
+        self.language = language;
+        self
+    }
+
+    /// Split lines into the tokens that have a meaning in the `Language` of the comparer
+    ///
+    /// Comments and whitespace are dropped, except for the indentation of Python code. Trailing
+    /// commas that a formatter may add or remove are dropped for languages that allow them, except
+    /// in parentheses around a single element, where the comma makes a tuple, such as `(x,)`.
+    /// Parentheses directly after an identifier, a `)` or a `]` hold arguments, such as `f(x,)`,
+    /// so their trailing comma is dropped.
+    fn significant_tokens(&self, lines: &[String]) -> Vec<Token> {
+        let lexer = Lexer::new(self.language);
+        let tokens = lexer.tokenize(&lines.join("\n"));
+
+        let mut significant: Vec<Token> = vec![];
+        // Whether every group that is still open can be a tuple and the number of its commas
+        let mut groups: Vec<(bool, usize)> = vec![];
+        let mut indentation = Some(String::new());
+
+        for token in tokens {
+            match token.kind {
+                TokenKind::Comment => continue,
+                TokenKind::Whitespace => {
+                    if self.language == Language::Python
+                        && groups.is_empty()
+                        && token.text.contains('\n')
+                    {
+                        let indent = token.text.rsplit('\n').next().unwrap_or_default();
+                        indentation = Some(String::from(indent));
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+
+            if self.language == Language::Python {
+                if let Some(indent) = indentation.take() {
+                    if groups.is_empty() {
+                        significant.push(Token {
+                            kind: TokenKind::Whitespace,
+                            text: indent,
+                            line: token.line,
+                        });
+                    }
+                }
+            }
+
+            let text = token.text.as_str();
+            if token.kind == TokenKind::Punctuation {
+                match text {
+                    "(" => {
+                        let follows_operand = significant.last().is_some_and(|t| {
+                            t.kind == TokenKind::Identifier || t.text == ")" || t.text == "]"
+                        });
+                        groups.push((!follows_operand, 0));
+                    }
+                    "[" | "{" => groups.push((false, 0)),
+                    "," => {
+                        if let Some((_, commas)) = groups.last_mut() {
+                            *commas += 1;
+                        }
+                    }
+                    ")" | "]" | "}" => {
+                        let single_element_tuple = groups
+                            .pop()
+                            .is_some_and(|(is_tuple, commas)| is_tuple && commas < 2);
+                        if self.allows_trailing_commas()
+                            && !single_element_tuple
+                            && significant.last().is_some_and(|t| t.text == ",")
+                        {
+                            significant.pop();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            significant.push(Token {
+                text: self.normalize(&token),
+                ..token
+            });
+        }
+
+        significant
+    }
+
+    /// Check whether a trailing comma before a closing bracket has no meaning in the `Language`
+    fn allows_trailing_commas(&self) -> bool {
+        matches!(
+            self.language,
+            Language::Rust
+                | Language::Go
+                | Language::Python
+                | Language::JavaScript
+                | Language::TypeScript
+        )
+    }
+
+    /// Normalize the text of a token so that equivalent spellings compare as equal
+    fn normalize(&self, token: &Token) -> String {
+        match token.kind {
+            TokenKind::Keyword if !self.language.has_case_sensitive_keywords() => {
+                token.text.to_lowercase()
+            }
+            TokenKind::String
+                if matches!(
+                    self.language,
+                    Language::Python | Language::JavaScript | Language::TypeScript
+                ) && token.text.starts_with('\'')
+                    && token.text.ends_with('\'')
+                    && token.text.len() > 1 =>
+            {
+                let content = &token.text[1..token.text.len() - 1];
+                if content.contains('"') || content.contains('\'') {
+                    token.text.clone()
+                } else {
+                    format!("\"{}\"", content)
+                }
+            }
+            _ => token.text.clone(),
+        }
+    }
+}
+
+impl IVectorComparer<String> for TokenComparer {
+    /// Initialize a new `TokenComparer` for type `String`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::token_comparer::TokenComparer;
+    /// # use text_diff::vector_comparer::IVectorComparer;
+    /// let token_comparer: TokenComparer = IVectorComparer::<String>::new(vec![], vec![]);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `TokenComparer` that can be used to compare two `Vec` structs of type `String` token by token
+    fn new(vec1: Vec<String>, vec2: Vec<String>) -> TokenComparer {
+        TokenComparer {
+            vec1,
+            vec2,
+            language: Language::default(),
+        }
+    }
+
+    /// Get the differences between the tokens of the two given `Vec` structs of type `String`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::token_comparer::TokenComparer;
+    /// # use text_diff::vector_comparer::IVectorComparer;
+    /// # let token_comparer: TokenComparer = IVectorComparer::<String>::new(vec![], vec![]);
+    /// let differences: Vec<String> = token_comparer.get_differences();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` struct of type `String` that contains the lines of tokens that were removed from or added to the first `Vec`
+    fn get_differences(&self) -> Vec<String> {
+        self.get_located_differences()
+            .into_iter()
+            .map(|d| d.text)
+            .collect()
+    }
+}
+
+impl TokenComparer {
+    /// Get the differences between the tokens of the two given `Vec` structs of type `String` and
+    /// where they were found
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::token_comparer::TokenComparer;
+    /// # use text_diff::vector_comparer::{Difference, IVectorComparer};
+    /// # let token_comparer: TokenComparer = IVectorComparer::<String>::new(vec![], vec![]);
+    /// let differences: Vec<Difference> = token_comparer.get_located_differences();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of `Difference` values with the line that contains the tokens of each difference
+    pub fn get_located_differences(&self) -> Vec<Difference> {
+        let tokens1 = self.significant_tokens(&self.vec1);
+        let tokens2 = self.significant_tokens(&self.vec2);
+
+        let texts1: Vec<&str> = tokens1.iter().map(|t| t.text.as_str()).collect();
+        let texts2: Vec<&str> = tokens2.iter().map(|t| t.text.as_str()).collect();
+
+        let mut diff_lines = vec![];
+        for op in diff(&texts1, &texts2) {
+            match op {
+                DiffOp::Equal { .. } => {}
+                DiffOp::Delete {
+                    old_index, old_len, ..
+                } => join_lines(
+                    &tokens1,
+                    old_index,
+                    old_index + old_len,
+                    Origin::First,
+                    &mut diff_lines,
+                ),
+                DiffOp::Insert {
+                    new_index, new_len, ..
+                } => join_lines(
+                    &tokens2,
+                    new_index,
+                    new_index + new_len,
+                    Origin::Second,
+                    &mut diff_lines,
+                ),
+            }
+        }
+
+        diff_lines
+    }
+}
+
+/// Join a run of tokens into one line of text per source line
+///
+/// When the indentation of a line changed, the rest of that line is included so that the line can
+/// be recognized.
+fn join_lines(
+    tokens: &[Token],
+    start: usize,
+    mut end: usize,
+    origin: Origin,
+    lines: &mut Vec<Difference>,
+) {
+    if tokens[end - 1].kind == TokenKind::Whitespace {
+        let line = tokens[end - 1].line;
+        while end < tokens.len()
+            && tokens[end].line == line
+            && tokens[end].kind != TokenKind::Whitespace
+        {
+            end += 1;
+        }
+    }
+
+    let mut current_line = None;
+    for token in &tokens[start..end] {
+        if current_line == Some(token.line) {
+            if let Some(last) = lines.last_mut() {
+                if !last.text.trim().is_empty() {
+                    last.text.push(' ');
+                }
+                last.text.push_str(&token.text);
+            }
+        } else {
+            lines.push(Difference::new(origin, token.line, token.text.clone()));
+            current_line = Some(token.line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn differences(language: Language, first: &str, second: &str) -> Vec<String> {
+        let token_comparer: TokenComparer =
+            IVectorComparer::<String>::new(vec![String::from(first)], vec![String::from(second)]);
+        token_comparer.with_language(language).get_differences()
+    }
+
+    #[test]
+    fn differences_are_located_in_their_file() {
+        let first = vec![String::from("let a = 1;"), String::from("let b = 2;")];
+        let second = vec![String::from("let a = 1;"), String::from("let b = 3;")];
+        let token_comparer: TokenComparer = IVectorComparer::<String>::new(first, second);
+        assert_eq!(
+            token_comparer
+                .with_language(Language::Rust)
+                .get_located_differences(),
+            vec![
+                Difference::new(Origin::First, 1, String::from("2")),
+                Difference::new(Origin::Second, 1, String::from("3")),
+            ]
+        );
+    }
+
+    #[test]
+    fn trailing_commas_are_ignored() {
+        assert!(differences(Language::Rust, "f(a, b,);", "f(a, b);").is_empty());
+        assert!(differences(Language::Rust, "let v = [a,];", "let v = [a];").is_empty());
+        assert!(differences(Language::Python, "d = {'a': 1,}", "d = {'a': 1}").is_empty());
+    }
+
+    #[test]
+    fn single_element_tuples_are_kept() {
+        assert!(!differences(Language::Python, "x = (1,)", "x = (1)").is_empty());
+        assert!(!differences(Language::Rust, "let t = (a,);", "let t = (a);").is_empty());
+    }
+
+    #[test]
+    fn trailing_commas_of_single_arguments_are_dropped() {
+        assert!(differences(Language::Rust, "f(a,);", "f(a);").is_empty());
+        assert!(differences(Language::Rust, "f(\n    a,\n);", "f(a);").is_empty());
+        assert!(differences(Language::Python, "x = f(a,)", "x = f(a)").is_empty());
+        assert!(differences(Language::Python, "x = g()(a,)", "x = g()(a)").is_empty());
+    }
+
+    #[test]
+    fn nested_groups_count_their_own_commas() {
+        assert!(!differences(Language::Rust, "f(a, (b,));", "f(a, (b));").is_empty());
+        assert!(differences(Language::Rust, "f((a,), b,);", "f((a,), b);").is_empty());
+    }
+
+    #[test]
+    fn trailing_commas_are_kept_in_other_languages() {
+        assert!(!differences(Language::Java, "f(a, b,);", "f(a, b);").is_empty());
+    }
+}
@@ -1,8 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+use crate::syntax::Language;
+use crate::token_comparer::TokenComparer;
+
+/// The strategy that is used to compare two files
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum CompareMode {
+    #[default]
+    Line,
+    Token,
+}
+
+impl CompareMode {
+    pub const ALL: [CompareMode; 2] = [CompareMode::Line, CompareMode::Token];
+
+    /// Get the differences between two `Vec` structs of type `String` using this `CompareMode`
+    ///
+    /// # Arguments
+    ///
+    /// * `vec1` - The lines of the first file
+    /// * `vec2` - The lines of the second file
+    /// * `language` - The `Language` of the files, which is used when comparing tokens
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::syntax::Language;
+    /// # use text_diff::vector_comparer::CompareMode;
+    /// let differences: Vec<String> = CompareMode::Token.get_differences(vec![], vec![], Language::Rust);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` struct of type `String` that contains the differences between the two given `Vec` structs of type `String`
+    pub fn get_differences(
+        &self,
+        vec1: Vec<String>,
+        vec2: Vec<String>,
+        language: Language,
+    ) -> Vec<String> {
+        self.get_located_differences(vec1, vec2, language)
+            .into_iter()
+            .map(|d| d.text)
+            .collect()
+    }
+
+    /// Get the differences between two `Vec` structs of type `String` and where they were found
+    ///
+    /// # Arguments
+    ///
+    /// * `vec1` - The lines of the first file
+    /// * `vec2` - The lines of the second file
+    /// * `language` - The `Language` of the files, which is used when comparing tokens
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::syntax::Language;
+    /// # use text_diff::vector_comparer::{CompareMode, Difference};
+    /// let differences: Vec<Difference> = CompareMode::Line.get_located_differences(vec![], vec![], Language::Rust);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of `Difference` values in the same order as the result of `get_differences`
+    pub fn get_located_differences(
+        &self,
+        vec1: Vec<String>,
+        vec2: Vec<String>,
+        language: Language,
+    ) -> Vec<Difference> {
+        match self {
+            CompareMode::Line => {
+                let vector_comparer: VectorComparer<String> =
+                    IVectorComparer::<String>::new(vec1, vec2);
+                vector_comparer.get_located_differences()
+            }
+            CompareMode::Token => {
+                let token_comparer: TokenComparer = IVectorComparer::<String>::new(vec1, vec2);
+                token_comparer
+                    .with_language(language)
+                    .get_located_differences()
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for CompareMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompareMode::Line => write!(f, "Lines"),
+            CompareMode::Token => write!(f, "Tokens (ignore comments and formatting)"),
+        }
+    }
+}
+
+/// The file that a difference was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Origin {
+    First,
+    Second,
+}
+
+/// A difference between two files and the line of the file where it was found
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Difference {
+    pub origin: Origin,
+    pub line: usize,
+    pub text: String,
+}
+
+impl Difference {
+    /// Initialize a new `Difference`
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The file that the difference was found in
+    /// * `line` - The index of the line in that file, starting at `0`
+    /// * `text` - The text of the difference
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::vector_comparer::{Difference, Origin};
+    /// let difference = Difference::new(Origin::First, 0, String::from("a"));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `Difference` instance
+    pub fn new(origin: Origin, line: usize, text: String) -> Difference {
+        Difference { origin, line, text }
+    }
+}
+
+/// Compares two `Vec` structs
 pub trait IVectorComparer<T> {
     fn new(vec1: Vec<T>, vec2: Vec<T>) -> Self;
     fn get_differences(&self) -> Vec<T>;
 }
 
+/// Compares two `Vec` structs line by line, regardless of the position of the lines
 #[derive(Debug, Clone)]
 pub struct VectorComparer<T> {
     pub vec1: Vec<T>,
@@ -15,6 +154,7 @@ impl IVectorComparer<String> for VectorComparer<String> {
     /// # Example
     ///
     /// ```rust
+    /// # use text_diff::vector_comparer::{IVectorComparer, VectorComparer};
     /// let vector_comparer: VectorComparer<String> = IVectorComparer::<String>::new(vec![], vec![]);
     /// ```
     ///
@@ -30,6 +170,8 @@ impl IVectorComparer<String> for VectorComparer<String> {
     /// # Example
     ///
     /// ```rust
+    /// # use text_diff::vector_comparer::{IVectorComparer, VectorComparer};
+    /// # let vector_comparer: VectorComparer<String> = IVectorComparer::<String>::new(vec![], vec![]);
     /// let differences: Vec<String> = vector_comparer.get_differences();
     /// ```
     ///
@@ -37,39 +179,52 @@ impl IVectorComparer<String> for VectorComparer<String> {
     ///
     /// A `Vec` struct of type `String` that contains the differences between the two given `Vec` structs of type `String`
     fn get_differences(&self) -> Vec<String> {
+        self.get_located_differences()
+            .into_iter()
+            .map(|d| d.text)
+            .collect()
+    }
+}
+
+impl VectorComparer<String> {
+    /// Get the differences between the two given `Vec` structs of type `String` and where they
+    /// were found
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::vector_comparer::{Difference, IVectorComparer, VectorComparer};
+    /// # let vector_comparer: VectorComparer<String> = IVectorComparer::<String>::new(vec![], vec![]);
+    /// let differences: Vec<Difference> = vector_comparer.get_located_differences();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of `Difference` values with the first line that contains each difference
+    pub fn get_located_differences(&self) -> Vec<Difference> {
+        let all = |lines: &[String], origin: Origin| {
+            lines
+                .iter()
+                .enumerate()
+                .map(|(i, l)| Difference::new(origin, i, l.clone()))
+                .collect()
+        };
         if self.vec1.is_empty() {
-            return self.vec2.clone();
+            return all(&self.vec2, Origin::Second);
         } else if self.vec2.is_empty() {
-            return self.vec1.clone();
+            return all(&self.vec1, Origin::First);
         }
 
-        let mut diff = vec![];
-        for f in &self.vec1 {
-            let mut included = false;
-            for d in &self.vec2 {
-                if f.eq(d) {
-                    included = true;
-                }
-            }
-
-            if !included {
-                diff.push(String::from(f));
+        let mut diff: Vec<Difference> = vec![];
+        for (i, f) in self.vec1.iter().enumerate() {
+            if !self.vec2.contains(f) {
+                diff.push(Difference::new(Origin::First, i, String::from(f)));
             }
         }
 
-        for f in &self.vec2 {
-            let mut included = false;
-            for d in &self.vec1 {
-                if f.eq(d) {
-                    included = true;
-                }
-            }
-
-            if !included {
-                let n = String::from(f);
-                if !diff.contains(&n) {
-                    diff.push(n);
-                }
+        for (i, f) in self.vec2.iter().enumerate() {
+            if !self.vec1.contains(f) && !diff.iter().any(|d| d.text == *f) {
+                diff.push(Difference::new(Origin::Second, i, String::from(f)));
             }
         }
 
@@ -1,34 +1,87 @@
 use std::{fs::File, io::Write};
 
-#[derive(Debug, Clone)]
+use crate::statistics::Statistics;
+
+/// The file format of an export
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub enum ExportType {
+    #[default]
     Text,
     Csv,
     Json,
 }
 
+/// The error that occurred while exporting
+#[derive(Debug)]
 pub enum ExportError {
     IoError(std::io::Error),
     JsonError(serde_json::Error),
 }
 
-impl Default for ExportType {
-    fn default() -> Self {
-        ExportType::Text
+impl std::fmt::Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::IoError(e) => write!(f, "{}", e),
+            ExportError::JsonError(e) => write!(f, "Unable to create JSON: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExportError::IoError(e) => Some(e),
+            ExportError::JsonError(e) => Some(e),
+        }
     }
 }
 
+/// Exports a `Vec` to a file
 pub trait IVectorExporter<T> {
     fn new(vec: Vec<T>, export_type: ExportType, export_type: &str) -> Self;
 
     fn export(&self) -> Result<(), ExportError>;
 }
 
+/// Exports a `Vec` to a file in one of the supported `ExportType` formats
+///
+/// When `Statistics` are given, they are written before the differences: as a header block of a
+/// TXT file, as leading rows of a CSV file or next to the differences in a JSON object.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct VectorExporter<T> {
     pub vec: Vec<T>,
     pub export_type: ExportType,
     pub export_path: String,
+    pub statistics: Option<Statistics>,
+}
+
+impl<T> VectorExporter<T> {
+    /// Add the statistics of the comparison to the export
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::statistics::Statistics;
+    /// # use text_diff::vector_exporter::{ExportType, IVectorExporter, VectorExporter};
+    /// let vec_exporter: VectorExporter<String> = IVectorExporter::<String>::new(vec![], ExportType::default(), "/path/to/file");
+    /// let vec_exporter: VectorExporter<String> = vec_exporter.with_statistics(Statistics::default());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `statistics` - The `Statistics` that are written before the differences
+    ///
+    /// # Returns
+    ///
+    /// The `VectorExporter` that writes the statistics
+    pub fn with_statistics(self, statistics: Statistics) -> VectorExporter<T> {
+        VectorExporter {
+            statistics: Some(statistics),
+            ..self
+        }
+    }
 }
 
 impl IVectorExporter<String> for VectorExporter<String> {
@@ -37,6 +90,7 @@ impl IVectorExporter<String> for VectorExporter<String> {
     /// # Example
     ///
     /// ```rust
+    /// # use text_diff::vector_exporter::{ExportType, IVectorExporter, VectorExporter};
     /// let vec_exporter: VectorExporter<String> = IVectorExporter::<String>::new(vec![], ExportType::default(), "/path/to/file");
     /// ```
     ///
@@ -48,14 +102,20 @@ impl IVectorExporter<String> for VectorExporter<String> {
             vec,
             export_type,
             export_path: String::from(export_path),
+            statistics: None,
         }
     }
 
     /// Export the `Vec` of type `String` to a file
     ///
+    /// The contents are created before the file, so that a failure does not leave an empty file
+    /// behind.
+    ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
+    /// # use text_diff::vector_exporter::{ExportType, IVectorExporter, VectorExporter};
+    /// # let vec_exporter: VectorExporter<String> = IVectorExporter::<String>::new(vec![], ExportType::default(), "/path/to/file");
     /// let res = vec_exporter.export();
     /// ```
     ///
@@ -63,45 +123,125 @@ impl IVectorExporter<String> for VectorExporter<String> {
     ///
     /// A `Result` that can either contain an `Ok` or an `Error` struct
     fn export(&self) -> Result<(), ExportError> {
+        let data = self.data()?;
+
         let file = File::create(&self.export_path);
         let mut file = match file {
             Ok(file) => file,
             Err(e) => return Err(ExportError::IoError(e)),
         };
+
+        match write!(file, "{}", data) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(ExportError::IoError(e)),
+        }
+    }
+}
+
+impl VectorExporter<String> {
+    /// Create the contents of the exported file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_diff::vector_exporter::{ExportType, IVectorExporter, VectorExporter};
+    /// let vec_exporter: VectorExporter<String> = IVectorExporter::<String>::new(vec![String::from("a")], ExportType::Csv, "/path/to/file.csv");
+    /// assert_eq!(vec_exporter.data().unwrap(), "\"a\"\n");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `Result` that contains the contents in the format of the `ExportType` or an `Error`
+    pub fn data(&self) -> Result<String, ExportError> {
+        let entries = match &self.statistics {
+            Some(d) => d.entries(),
+            None => vec![],
+        };
+
         match self.export_type {
             ExportType::Text => {
                 let mut data = String::new();
+                for (label, value) in &entries {
+                    data.push_str(&format!("{}: {}\n", label, value));
+                }
+                if !entries.is_empty() {
+                    data.push('\n');
+                }
                 for l in &self.vec {
                     data.push_str(&format!("{}\n", l));
                 }
 
-                match write!(file, "{}", data) {
-                    Ok(_) => Ok(()),
-                    Err(e) => Err(ExportError::IoError(e)),
-                }
+                Ok(data)
             }
             ExportType::Csv => {
                 let mut data = String::new();
+                for (label, value) in &entries {
+                    data.push_str(&format!("\"{}\",\"{}\"\n", label, value));
+                }
                 for l in &self.vec {
                     data.push_str(&format!("\"{}\"\n", l));
                 }
 
-                match write!(file, "{}", data) {
-                    Ok(_) => Ok(()),
-                    Err(e) => Err(ExportError::IoError(e)),
-                }
+                Ok(data)
             }
             ExportType::Json => {
-                let serialized = match serde_json::to_string(&self.vec) {
-                    Ok(d) => d,
-                    Err(e) => return Err(ExportError::JsonError(e)),
+                let serialized = match &self.statistics {
+                    Some(d) => serde_json::to_string(&serde_json::json!({
+                        "statistics": d,
+                        "differences": &self.vec,
+                    })),
+                    None => serde_json::to_string(&self.vec),
                 };
 
-                match write!(file, "{}", serialized) {
-                    Ok(_) => Ok(()),
-                    Err(e) => Err(ExportError::IoError(e)),
+                match serialized {
+                    Ok(d) => Ok(d),
+                    Err(e) => Err(ExportError::JsonError(e)),
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exporter(export_type: ExportType) -> VectorExporter<String> {
+        let statistics = Statistics {
+            first_lines: 2,
+            ..Statistics::default()
+        };
+        let vec_exporter: VectorExporter<String> =
+            IVectorExporter::<String>::new(vec![String::from("a")], export_type, "/path/to/file");
+        vec_exporter.with_statistics(statistics)
+    }
+
+    #[test]
+    fn text_starts_with_the_statistics() {
+        let data = exporter(ExportType::Text).data().unwrap();
+        assert!(data.starts_with("Lines in the first file: 2\n"));
+        assert!(data.ends_with("Bytes in the second file: 0\n\na\n"));
+    }
+
+    #[test]
+    fn csv_starts_with_rows_of_statistics() {
+        let data = exporter(ExportType::Csv).data().unwrap();
+        assert!(data.starts_with("\"Lines in the first file\",\"2\"\n"));
+        assert!(data.ends_with("\"Bytes in the second file\",\"0\"\n\"a\"\n"));
+    }
+
+    #[test]
+    fn json_contains_the_statistics_and_the_differences() {
+        let data = exporter(ExportType::Json).data().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&data).unwrap();
+        assert_eq!(value["statistics"]["first_lines"], 2);
+        assert_eq!(value["differences"], serde_json::json!(["a"]));
+    }
+
+    #[test]
+    fn differences_without_statistics() {
+        let vec_exporter: VectorExporter<String> =
+            IVectorExporter::<String>::new(vec![String::from("a")], ExportType::Json, "");
+        assert_eq!(vec_exporter.data().unwrap(), "[\"a\"]");
+    }
+}
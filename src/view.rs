@@ -1,275 +1,3915 @@
-use std::ffi::{OsStr, OsString};
-use std::path::Path;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::time::{Duration, Instant};
 
-use crate::file_reader::FileReader;
+use crate::cli::Arguments;
+use crate::file_watcher;
+use crate::notification::{Notifications, Severity};
+use crate::overview_ruler::OverviewRuler;
+use crate::session::{self, Session};
+use crate::settings::{revision_name, RecentPair, Settings, TabSettings};
 use crate::style;
-use crate::vector_comparer::{IVectorComparer, VectorComparer};
-use crate::vector_exporter::{ExportType, IVectorExporter, VectorExporter};
-use iced::{alignment, scrollable, Rule, Scrollable};
+use crate::system_theme;
+use crate::text_area::{Side, TextArea};
+use crate::timer;
+use crate::virtual_list::VirtualList;
+use iced::{alignment, clipboard, executor, scrollable, Command, Rule, Scrollable, Subscription};
 use iced::{
-    button, text_input, Alignment, Button, Column, Container, Element, Length, Radio, Row, Sandbox,
-    Text, TextInput,
+    button, pick_list, text_input, Alignment, Application, Button, Checkbox, Color, Column,
+    Container, Element, Length, PickList, Radio, Row, Space, Text, TextInput,
 };
+use iced_native::{event, keyboard, window, Event};
 use native_dialog::{FileDialog, MessageDialog, MessageType};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use text_diff::diff::{self, Hunk};
+use text_diff::error::Error;
+use text_diff::file_reader::{FileReader, FileSource, LineFormat, Revision};
+use text_diff::folder_comparer::{FolderComparer, FolderEntry};
+use text_diff::ignore_rules::IgnoreRules;
+use text_diff::merge::{MergeChunk, Resolution, ThreeWayMerger};
+use text_diff::statistics::Statistics;
+use text_diff::syntax::{Language, Lexer};
+use text_diff::vector_comparer::{CompareMode, Difference, Origin};
+use text_diff::vector_exporter::{ExportType, IVectorExporter, VectorExporter};
 
 #[derive(Debug, Clone)]
 pub enum Message {
     ThemeChanged(style::Theme),
+    CompareModeChanged(CompareMode),
     FirstFileInputChanged(String),
     SecondFileInputChanged(String),
+    FirstFileInputSubmitted,
+    SecondFileInputSubmitted,
+    FirstRevisionChanged(Revision),
+    SecondRevisionChanged(Revision),
     SelectFirstFilePressed,
     SelectSecondFilePressed,
     ComparePressed,
     ClearComparePressed,
     ExportPressed,
+    ResolutionChanged(usize, Resolution),
+    SaveMergePressed,
+    AbortMergePressed,
+    NativeDialogsToggled(bool),
+    AutoReloadToggled(bool),
+    FileChanged(String),
+    NotificationDismissed(usize),
+    IgnoreRuleInputChanged(String),
+    IgnoreRuleSubmitted,
+    IgnoreRuleRemoved(usize),
+    RecentSelected(RecentPair),
+    SystemThemeChanged(bool),
+    FileDropped(PathBuf),
+    FolderEntrySelected(usize),
+    InputModeChanged(InputMode),
+    TextLineChanged(Side, usize, String),
+    TextLineSubmitted(Side, usize),
+    TextScrolled(Side, f32),
+    SaveTextPressed(Side),
+    TextUndoPressed(Side),
+    TextRedoPressed(Side),
+    TimerTicked(Instant),
+    ViewModeChanged(ViewMode),
+    HunkCopiedToSecond(usize),
+    HunkCopiedToFirst(usize),
+    HunkReverted(usize),
+    ContextLinesChanged(usize),
+    FoldExpanded(usize, bool),
+    ListScrolled(f32),
+    OverviewPressed(usize),
+    LineNumberPressed(Side, usize),
+    CopyLocationPressed,
+    TabSelected(usize),
+    TabClosed(usize),
+    NewTabPressed,
+    OpenSessionPressed,
+    SaveSessionPressed,
+    SideBySideScrolled(f32),
+    NextChangePressed,
+    PreviousChangePressed,
+    ShortcutPressed(Shortcut),
+    SideFilterChanged(SideFilter),
+    FilterPatternChanged(String),
+    HideWhitespaceToggled(bool),
+    ExportFilteredToggled(bool),
+    SearchChanged(String),
+    SearchSubmitted,
+    NextMatchPressed,
+    PreviousMatchPressed,
+    WindowResized(u32, u32),
+    CloseRequested,
+    SaveEditsPressed,
+    DiscardEditsPressed,
+    CancelActionPressed,
+}
+
+/// The source of the lines that are compared
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputMode {
+    #[default]
+    Files,
+    Text,
+}
+
+impl InputMode {
+    pub const ALL: [InputMode; 2] = [InputMode::Files, InputMode::Text];
+}
+
+impl std::fmt::Display for InputMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                InputMode::Files => "Files",
+                InputMode::Text => "Text",
+            }
+        )
+    }
+}
+
+/// The way in which the differences are displayed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ViewMode {
+    #[default]
+    List,
+    SideBySide,
+}
+
+impl ViewMode {
+    pub const ALL: [ViewMode; 2] = [ViewMode::List, ViewMode::SideBySide];
+}
+
+impl std::fmt::Display for ViewMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ViewMode::List => "Differences",
+                ViewMode::SideBySide => "Side by side",
+            }
+        )
+    }
+}
+
+/// The kinds of changes that are shown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SideFilter {
+    #[default]
+    All,
+    FirstOnly,
+    SecondOnly,
+    Changed,
+}
+
+impl SideFilter {
+    pub const ALL: [SideFilter; 4] = [
+        SideFilter::All,
+        SideFilter::FirstOnly,
+        SideFilter::SecondOnly,
+        SideFilter::Changed,
+    ];
+
+    /// Get the kind of a hunk
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let kind: SideFilter = SideFilter::of(&hunk)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `hunk` - The `Hunk` whose lines were removed, added or changed
+    ///
+    /// # Returns
+    ///
+    /// `FirstOnly` for removed lines, `SecondOnly` for added lines and `Changed` otherwise
+    pub fn of(hunk: &Hunk) -> SideFilter {
+        if hunk.new_len == 0 {
+            SideFilter::FirstOnly
+        } else if hunk.old_len == 0 {
+            SideFilter::SecondOnly
+        } else {
+            SideFilter::Changed
+        }
+    }
+}
+
+impl std::fmt::Display for SideFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SideFilter::All => "All changes",
+                SideFilter::FirstOnly => "Only in first",
+                SideFilter::SecondOnly => "Only in second",
+                SideFilter::Changed => "Changed",
+            }
+        )
+    }
+}
+
+/// The conditions that a change has to meet to be shown
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Filter {
+    pub side: SideFilter,
+    pub pattern: String,
+    pub hide_whitespace: bool,
+}
+
+impl Filter {
+    /// Check whether a change meets the conditions of the filter
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let accepted: bool = filter.accepts(SideFilter::Changed, false, &["line"], None)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the change
+    /// * `whitespace_only` - Whether the change only differs in whitespace
+    /// * `lines` - The lines of the change
+    /// * `regex` - The compiled pattern of the filter, if it is valid
+    ///
+    /// # Returns
+    ///
+    /// `true` if the change should be shown
+    pub fn accepts<S: AsRef<str>>(
+        &self,
+        kind: SideFilter,
+        whitespace_only: bool,
+        lines: &[S],
+        regex: Option<&Regex>,
+    ) -> bool {
+        if self.side != SideFilter::All && self.side != kind {
+            return false;
+        }
+
+        if self.hide_whitespace && whitespace_only {
+            return false;
+        }
+
+        match regex {
+            Some(r) => lines.iter().any(|l| r.is_match(l.as_ref())),
+            None => true,
+        }
+    }
+}
+
+/// An action that is triggered by a keyboard shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shortcut {
+    Compare,
+    OpenFiles,
+    Export,
+    Clear,
+    NewTab,
+    ToggleTheme,
+    NextChange,
+    PreviousChange,
+}
+
+impl Shortcut {
+    /// Get the shortcut of a key press
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let shortcut: Option<Shortcut> = Shortcut::from_key(keyboard::KeyCode::F7, keyboard::Modifiers::default())
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `key_code` - The key that was pressed
+    /// * `modifiers` - The modifier keys that were held down
+    ///
+    /// # Returns
+    ///
+    /// The `Shortcut` that belongs to the key press or `None` if there is none
+    pub fn from_key(
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) -> Option<Shortcut> {
+        use keyboard::KeyCode;
+
+        match key_code {
+            KeyCode::F7 if modifiers.shift() => Some(Shortcut::PreviousChange),
+            KeyCode::F7 => Some(Shortcut::NextChange),
+            KeyCode::Up if modifiers.alt() => Some(Shortcut::PreviousChange),
+            KeyCode::Down if modifiers.alt() => Some(Shortcut::NextChange),
+            KeyCode::Enter if modifiers.command() => Some(Shortcut::Compare),
+            KeyCode::O if modifiers.command() => Some(Shortcut::OpenFiles),
+            KeyCode::E if modifiers.command() => Some(Shortcut::Export),
+            KeyCode::Delete if modifiers.command() => Some(Shortcut::Clear),
+            KeyCode::N | KeyCode::T if modifiers.command() => Some(Shortcut::NewTab),
+            KeyCode::L if modifiers.command() && modifiers.shift() => Some(Shortcut::ToggleTheme),
+            _ => None,
+        }
+    }
+}
+
+/// The values that are passed to the application on startup
+pub struct Flags {
+    pub arguments: Arguments,
+    pub settings: Settings,
+    pub errors: Vec<Error>,
+}
+
+/// The exit code of the application, which tells git whether a merge was completed
+pub static EXIT_CODE: AtomicI32 = AtomicI32::new(0);
+
+/// The time in which dropped files are considered to be part of the same drop
+const DROP_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The time without edits after which the differences are calculated again
+const REDIFF_DELAY: Duration = Duration::from_millis(300);
+
+/// The interval at which the deadlines of delayed updates are checked
+const TIMER_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The number of unchanged lines that are shown when a folded region is expanded step by step
+const EXPAND_STEP: usize = 20;
+
+/// The height of a row of the differences, in pixels
+const ROW_HEIGHT: u16 = 30;
+
+/// The height of the list of differences, in pixels
+const LIST_HEIGHT: u16 = 150;
+
+/// The height of the side-by-side view, in pixels
+const SIDE_BY_SIDE_HEIGHT: u16 = 300;
+
+/// The numbers of unchanged lines around a change that can be selected
+const CONTEXT_SIZES: [usize; 6] = [0, 1, 3, 5, 10, 25];
+
+/// The width of the line numbers of the side-by-side view, in pixels
+const GUTTER_WIDTH: u16 = 60;
+
+/// A range of lines on one side of the side-by-side view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRange {
+    pub side: Side,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl LineRange {
+    /// Check whether the range contains a line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let contains: bool = line_range.contains(Side::First, 12);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The side of the line
+    /// * `line` - The index of the line
+    ///
+    /// # Returns
+    ///
+    /// `true` if the line is on the same side and within the range
+    pub fn contains(&self, side: Side, line: usize) -> bool {
+        self.side == side && line >= self.start && line <= self.end
+    }
+}
+
+/// A row of the side-by-side view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayRow {
+    Unchanged {
+        first: Option<usize>,
+        second: Option<usize>,
+    },
+    Changed {
+        first: Option<usize>,
+        second: Option<usize>,
+    },
+    Actions(usize),
+    Folded {
+        region: usize,
+        lines: usize,
+    },
 }
 
 #[derive(Default)]
-pub struct ApplicationContext {
-    pub theme: style::Theme,
-    pub first_file: String,
-    pub second_file: String,
-    pub first_file_input: text_input::State,
-    pub second_file_input: text_input::State,
-    pub btn_select_first_file: button::State,
-    pub btn_select_second_file: button::State,
-    pub btn_compare: button::State,
-    pub btn_clean_compare: button::State,
-    pub btn_export: button::State,
-    pub scrollable: scrollable::State,
-    pub differences: Vec<String>,
-    pub has_compared: bool,
+pub struct SideBySideContext {
+    pub hunks: Vec<Hunk>,
+    pub visible: Vec<bool>,
+    pub context: usize,
+    pub expanded: HashMap<usize, usize>,
+    pub rows: Vec<DisplayRow>,
+    pub btn_hunks: Vec<[button::State; 3]>,
+    pub btn_folds: Vec<[button::State; 2]>,
+    pub btn_gutters: Vec<[button::State; 2]>,
+    pub selection: Option<LineRange>,
+    pub list: VirtualList,
+    pub is_outdated: bool,
 }
 
-impl ApplicationContext {
-    /// Display a native alert
+impl SideBySideContext {
+    /// Replace the hunks that are displayed
+    ///
+    /// Expanded regions stay expanded as long as the number of hunks does not change, so that
+    /// editing a line does not fold the view again.
     ///
     /// # Example
     ///
     /// ```rust
-    /// display_alert("hello", "world", MessageType::Info)
+    /// set_hunks(hunks)
     /// ```
     ///
     /// # Arguments
     ///
-    /// * `title` - The alert title
-    /// * `content` - the content of the alert
-    /// * `message_type` - The `MessageType` for the alert
-    fn display_alert(&self, title: &str, content: &str, message_type: MessageType) {
-        MessageDialog::new()
-            .set_type(message_type)
-            .set_title(title)
-            .set_text(content)
-            .show_alert()
-            .unwrap();
+    /// * `hunks` - The hunks between the first and second text
+    pub fn set_hunks(&mut self, hunks: Vec<Hunk>) {
+        if hunks.len() != self.hunks.len() {
+            self.expanded = HashMap::new();
+        }
+
+        self.btn_hunks = hunks.iter().map(|_| Default::default()).collect();
+        self.btn_folds = (0..=hunks.len()).map(|_| Default::default()).collect();
+        self.visible = vec![true; hunks.len()];
+        self.hunks = hunks;
+        self.is_outdated = false;
     }
 
-    /// Open the file dialog to select a file
+    /// Get a hunk, unless the lines were edited since the hunks were set
+    ///
+    /// The actions of the hunks are turned off until the lines are compared again, because the
+    /// positions of outdated hunks no longer match the lines.
     ///
     /// # Example
     ///
     /// ```rust
-    /// let selected_file_result: Result<Option<String>, OsString> = open_file_dialog()
+    /// let hunk: Option<Hunk> = hunk(0)
     /// ```
     ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the hunk
+    ///
     /// # Returns
     ///
-    /// The optional `String` that contains the path of the selected file or an `OsString` error
-    fn open_file_dialog() -> Result<Option<String>, OsString> {
-        let path = FileDialog::new()
-            .add_filter("Text file", &["txt"])
-            .add_filter("All files", &["*"])
-            .show_open_single_file()
-            .unwrap();
+    /// The `Hunk` or `None` if it does not exist or the hunks are outdated
+    pub fn hunk(&self, index: usize) -> Option<Hunk> {
+        if self.is_outdated {
+            return None;
+        }
+
+        self.hunks.get(index).copied()
+    }
+
+    /// Show more lines of a folded region of unchanged lines
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// expand(0, false)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The index of the region, which is the index of the hunk that follows it
+    /// * `all` - Whether every line or the next step of lines should be shown
+    pub fn expand(&mut self, region: usize, all: bool) {
+        let expanded = self.expanded.entry(region).or_insert(0);
+        *expanded = if all {
+            usize::MAX
+        } else {
+            expanded.saturating_add(EXPAND_STEP)
+        };
+    }
+
+    /// Calculate the rows of the view
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// layout(first.len(), second.len())
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `first_len` - The number of lines of the first text
+    /// * `second_len` - The number of lines of the second text
+    pub fn layout(&mut self, first_len: usize, second_len: usize) {
+        let mut rows = vec![];
+        let (mut first_index, mut second_index) = (0, 0);
+
+        for (index, hunk) in self.hunks.iter().enumerate() {
+            self.layout_region(
+                &mut rows,
+                index,
+                first_index..hunk.old_index,
+                second_index..hunk.new_index,
+                (index == 0, false),
+            );
+
+            first_index = hunk.old_index + hunk.old_len;
+            second_index = hunk.new_index + hunk.new_len;
+
+            // Hunks that are hidden by a filter are displayed like unchanged lines
+            let visible = self.visible.get(index).copied().unwrap_or(true);
+            if visible {
+                rows.push(DisplayRow::Actions(index));
+            }
+            paired_rows(
+                &mut rows,
+                hunk.old_index..first_index,
+                hunk.new_index..second_index,
+                visible,
+            );
+        }
+
+        self.layout_region(
+            &mut rows,
+            self.hunks.len(),
+            first_index..first_len,
+            second_index..second_len,
+            (self.hunks.is_empty(), true),
+        );
+
+        self.btn_gutters.resize_with(rows.len(), Default::default);
+        self.rows = rows;
+    }
+
+    /// Add the rows of a region of unchanged lines, folding the lines that are not near a change
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The rows that the region is added to
+    /// * `region` - The index of the region
+    /// * `first` - The range of the lines of the first text
+    /// * `second` - The range of the lines of the second text
+    /// * `edges` - Whether the region is at the start and at the end of the texts
+    fn layout_region(
+        &self,
+        rows: &mut Vec<DisplayRow>,
+        region: usize,
+        first: Range<usize>,
+        second: Range<usize>,
+        edges: (bool, bool),
+    ) {
+        let (is_start, is_end) = edges;
+        let count = usize::max(first.len(), second.len());
+        let expanded = self.expanded.get(&region).copied().unwrap_or(0);
+        let leading = if is_start { 0 } else { self.context }.saturating_add(expanded);
+        let trailing = if is_end { 0 } else { self.context };
+
+        // Folding a single line would not save any space
+        if leading.saturating_add(trailing).saturating_add(1) >= count {
+            paired_rows(rows, first, second, false);
+            return;
+        }
+
+        let offset = |range: &Range<usize>, start: usize, end: usize| {
+            usize::min(range.start + start, range.end)..usize::min(range.start + end, range.end)
+        };
+        paired_rows(
+            rows,
+            offset(&first, 0, leading),
+            offset(&second, 0, leading),
+            false,
+        );
+        rows.push(DisplayRow::Folded {
+            region,
+            lines: count - leading - trailing,
+        });
+        paired_rows(
+            rows,
+            offset(&first, count - trailing, count),
+            offset(&second, count - trailing, count),
+            false,
+        );
+    }
 
-        let path = match path {
-            Some(path) => path,
-            None => return Ok(None),
+    /// Select the line of a line number that was pressed
+    ///
+    /// Pressing another line number on the same side extends a selected line to a range and
+    /// pressing a selected line again clears the selection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// select_line(Side::First, 12)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The side of the line
+    /// * `line` - The index of the line
+    pub fn select_line(&mut self, side: Side, line: usize) {
+        self.selection = match self.selection {
+            Some(d) if d.side == side && d.start == line && d.end == line => None,
+            Some(d) if d.side == side && d.start == d.end => Some(LineRange {
+                side,
+                start: usize::min(d.start, line),
+                end: usize::max(d.start, line),
+            }),
+            _ => Some(LineRange {
+                side,
+                start: line,
+                end: line,
+            }),
         };
+    }
+
+    /// Scroll to a hunk
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// scroll_to(2)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the hunk
+    pub fn scroll_to(&mut self, index: usize) {
+        let position = self
+            .rows
+            .iter()
+            .position(|r| *r == DisplayRow::Actions(index));
 
-        match path.into_os_string().into_string() {
-            Ok(d) => Ok(Some(d)),
-            Err(e) => Err(e),
+        if let Some(d) = position {
+            self.list.scroll_to(d);
         }
     }
+
+    /// Scroll to a line of the first text
+    ///
+    /// A line that is folded scrolls to the folded region that contains it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// scroll_to_line(120)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The index of the line in the first text
+    pub fn scroll_to_line(&mut self, line: usize) {
+        let mut folded = None;
+        let position = self.rows.iter().enumerate().find_map(|(row, d)| match d {
+            DisplayRow::Folded { .. } => {
+                folded = Some(row);
+                None
+            }
+            DisplayRow::Unchanged { first: Some(d), .. }
+            | DisplayRow::Changed { first: Some(d), .. } => {
+                if *d >= line {
+                    Some(if *d > line {
+                        folded.unwrap_or(row)
+                    } else {
+                        row
+                    })
+                } else {
+                    folded = None;
+                    None
+                }
+            }
+            _ => None,
+        });
+
+        self.list
+            .scroll_to(position.unwrap_or(self.rows.len().saturating_sub(1)));
+    }
+
+    /// Create the view of both texts next to each other, with actions for every hunk
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let element: Scrollable<'_, Message> = view(&first, &second, Language::Rust, Some(0), "fn", theme)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The lines of the first text
+    /// * `second` - The lines of the second text
+    /// * `language` - The `Language` that is used for syntax highlighting
+    /// * `current` - The index of the hunk that was navigated to
+    /// * `query` - The search text that is highlighted
+    /// * `theme` - The `Theme` of the view
+    ///
+    /// # Returns
+    ///
+    /// The `Scrollable` that contains the rows of the view
+    pub fn view(
+        &mut self,
+        first: &[String],
+        second: &[String],
+        language: Language,
+        current: Option<usize>,
+        query: &str,
+        theme: style::Theme,
+    ) -> Scrollable<'_, Message> {
+        let lexer = Lexer::new(language);
+        let colors = theme.diff_colors();
+        let unchanged = [("  ", theme.text_color()), ("  ", theme.text_color())];
+        let changed = [("- ", colors.removed.0), ("+ ", colors.added.0)];
+        let is_outdated = self.is_outdated;
+
+        // The rows refer to the buttons in the same order as they are stored
+        let mut btn_hunks = self.btn_hunks.iter_mut().enumerate();
+        let mut btn_folds = self.btn_folds.iter_mut().enumerate();
+        let mut btn_gutters = self.btn_gutters.iter_mut();
+        let mut next_gutter = 0;
+        let selection = self.selection;
+
+        let rows = &self.rows;
+        self.list.view(
+            rows.len(),
+            ROW_HEIGHT,
+            SIDE_BY_SIDE_HEIGHT,
+            theme,
+            Message::SideBySideScrolled,
+            move |index| match rows[index] {
+                DisplayRow::Unchanged {
+                    first: a,
+                    second: b,
+                }
+                | DisplayRow::Changed {
+                    first: a,
+                    second: b,
+                } => {
+                    let (prefixes, query) = match rows[index] {
+                        DisplayRow::Changed { .. } => (changed, query),
+                        _ => (unchanged, ""),
+                    };
+                    let [first_gutter, second_gutter] = match btn_gutters.nth(index - next_gutter) {
+                        Some(d) => d,
+                        None => return Row::new().into(),
+                    };
+                    next_gutter = index + 1;
+
+                    side_by_side_row(
+                        &lexer,
+                        theme,
+                        prefixes,
+                        (line(first, a), line(second, b)),
+                        [
+                            gutter(first_gutter, Side::First, a, selection, theme),
+                            gutter(second_gutter, Side::Second, b, selection, theme),
+                        ],
+                        query,
+                    )
+                    .into()
+                }
+                DisplayRow::Actions(index) => {
+                    let [btn_to_second, btn_to_first, btn_revert] =
+                        match btn_hunks.find(|(i, _)| *i == index) {
+                            Some((_, d)) => d,
+                            None => return Row::new().into(),
+                        };
+                    let actions = [
+                        (btn_to_second, "Copy →", Message::HunkCopiedToSecond(index)),
+                        (btn_to_first, "← Copy", Message::HunkCopiedToFirst(index)),
+                        (btn_revert, "Revert", Message::HunkReverted(index)),
+                    ];
+                    let marker = if current == Some(index) { "▶" } else { "" };
+                    actions
+                        .into_iter()
+                        .fold(
+                            Row::new()
+                                .spacing(10)
+                                .push(Text::new(marker).color(colors.changed.0)),
+                            |row, (state, label, message)| {
+                                let mut button = Button::new(state, Text::new(label).size(16))
+                                    .padding(3)
+                                    .style(theme);
+                                if !is_outdated {
+                                    button = button.on_press(message);
+                                }
+                                row.push(button)
+                            },
+                        )
+                        .into()
+                }
+                DisplayRow::Folded { region, lines } => {
+                    let [btn_step, btn_all] = match btn_folds.find(|(i, _)| *i == region) {
+                        Some((_, d)) => d,
+                        None => return Row::new().into(),
+                    };
+                    Row::new()
+                        .spacing(10)
+                        .align_items(Alignment::Center)
+                        .push(
+                            Text::new(format!("⋯ {} unchanged lines", format_count(lines)))
+                                .color(theme.text_color()),
+                        )
+                        .push(
+                            Button::new(
+                                btn_step,
+                                Text::new(format!("Show {} more", EXPAND_STEP)).size(16),
+                            )
+                            .padding(3)
+                            .on_press(Message::FoldExpanded(region, false))
+                            .style(theme),
+                        )
+                        .push(
+                            Button::new(btn_all, Text::new("Show all").size(16))
+                                .padding(3)
+                                .on_press(Message::FoldExpanded(region, true))
+                                .style(theme),
+                        )
+                        .into()
+                }
+            },
+        )
+    }
 }
 
-impl Sandbox for ApplicationContext {
-    type Message = Message;
+/// Add the rows of two ranges of lines that are displayed next to each other
+///
+/// # Arguments
+///
+/// * `rows` - The rows that the lines are added to
+/// * `first` - The range of the lines of the first text
+/// * `second` - The range of the lines of the second text
+/// * `changed` - Whether the lines belong to a change
+fn paired_rows(
+    rows: &mut Vec<DisplayRow>,
+    first: Range<usize>,
+    second: Range<usize>,
+    changed: bool,
+) {
+    for offset in 0..usize::max(first.len(), second.len()) {
+        let a = Some(first.start + offset).filter(|i| *i < first.end);
+        let b = Some(second.start + offset).filter(|i| *i < second.end);
+        rows.push(if changed {
+            DisplayRow::Changed {
+                first: a,
+                second: b,
+            }
+        } else {
+            DisplayRow::Unchanged {
+                first: a,
+                second: b,
+            }
+        });
+    }
+}
+
+/// Get a line of a text, if the row refers to one
+fn line(lines: &[String], index: Option<usize>) -> Option<&String> {
+    index.and_then(|i| lines.get(i))
+}
+
+#[derive(Default)]
+pub struct MergeContext {
+    pub merged_file: String,
+    pub format: LineFormat,
+    pub chunks: Vec<MergeChunk>,
+    pub resolutions: Vec<Option<Resolution>>,
+    pub btn_resolutions: Vec<[button::State; 3]>,
+    pub btn_save: button::State,
+    pub btn_abort: button::State,
+    pub scrollable: scrollable::State,
+}
+
+/// The comparison that is shown in a tab
+#[derive(Default)]
+pub struct Comparison {
+    pub compare_mode: CompareMode,
+    pub first_file: String,
+    pub second_file: String,
+    pub first_revision: Revision,
+    pub second_revision: Revision,
+    pub first_revisions: Vec<Revision>,
+    pub second_revisions: Vec<Revision>,
+    pub differences: Vec<Difference>,
+    pub statistics: Option<Statistics>,
+    pub language: Language,
+    pub has_compared: bool,
+    pub ignore_rules: IgnoreRules,
+    pub btn_ignore_rules: Vec<button::State>,
+    pub folder_entries: Vec<FolderEntry>,
+    pub btn_folder_entries: Vec<button::State>,
+    pub is_folder_comparison: bool,
+    pub input_mode: InputMode,
+    pub first_text: TextArea,
+    pub second_text: TextArea,
+    pub rediff_at: Option<Instant>,
+    pub view_mode: ViewMode,
+    pub differences_list: VirtualList,
+    pub side_by_side: SideBySideContext,
+    pub current_change: Option<usize>,
+    pub filter: Filter,
+    pub filter_error: Option<String>,
+    pub visible_differences: Vec<usize>,
+    pub export_filtered: bool,
+    pub search: String,
+    pub pending_compare: bool,
+    pub has_revisions: bool,
+    pub reload_at: Option<Instant>,
+    pub changed_on_disk: bool,
+}
+
+impl Comparison {
+    /// Initialize a new `Comparison` with the options of the settings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let tab: Comparison = Comparison::new(&settings);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The `Settings` that contain the default options
+    ///
+    /// # Returns
+    ///
+    /// The `Comparison` without any files
+    pub fn new(settings: &Settings) -> Comparison {
+        Comparison {
+            compare_mode: settings.compare_mode,
+            btn_ignore_rules: settings
+                .ignore_rules
+                .patterns
+                .iter()
+                .map(|_| button::State::default())
+                .collect(),
+            ignore_rules: settings.ignore_rules.clone(),
+            view_mode: settings.view_mode,
+            side_by_side: SideBySideContext {
+                context: settings.context_lines,
+                ..SideBySideContext::default()
+            },
+            ..Comparison::default()
+        }
+    }
+
+    /// Initialize a `Comparison` that was stored in the settings
+    ///
+    /// The files or texts are compared again once the tab is selected, if they were compared
+    /// before. The git revisions of the files are only loaded then as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let tab: Comparison = Comparison::restore(&settings.tabs[0], &settings);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `tab` - The stored `TabSettings`
+    /// * `settings` - The `Settings` that contain the default options
+    ///
+    /// # Returns
+    ///
+    /// The `Comparison` with the stored files and options
+    pub fn restore(tab: &TabSettings, settings: &Settings) -> Comparison {
+        let has_inputs = match tab.input_mode {
+            InputMode::Files => !tab.first_file.is_empty() && !tab.second_file.is_empty(),
+            InputMode::Text => !tab.first_text.is_empty() || !tab.second_text.is_empty(),
+        };
+
+        let mut comparison = Comparison {
+            input_mode: tab.input_mode,
+            first_revision: Revision::parse(&tab.first_revision),
+            second_revision: Revision::parse(&tab.second_revision),
+            first_file: tab.first_file.clone(),
+            second_file: tab.second_file.clone(),
+            compare_mode: tab.compare_mode,
+            btn_ignore_rules: tab
+                .ignore_rules
+                .patterns
+                .iter()
+                .map(|_| button::State::default())
+                .collect(),
+            ignore_rules: tab.ignore_rules.clone(),
+            view_mode: tab.view_mode,
+            filter: tab.filter.clone(),
+            pending_compare: tab.has_compared && has_inputs,
+            ..Comparison::new(settings)
+        };
+
+        if tab.input_mode == InputMode::Text {
+            comparison
+                .first_text
+                .set_lines(tab.first_text.clone(), LineFormat::default());
+            comparison
+                .second_text
+                .set_lines(tab.second_text.clone(), LineFormat::default());
+        }
+
+        comparison
+    }
+
+    /// Load the git revisions of the files, unless they were loaded already
+    ///
+    /// Revisions that are not available for a file are replaced by the working tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// load_revisions()
+    /// ```
+    fn load_revisions(&mut self) {
+        if self.has_revisions {
+            return;
+        }
+
+        self.first_revisions = ApplicationContext::load_revisions(&self.first_file);
+        if self.first_revisions.is_empty() {
+            self.first_revision = Revision::WorkingTree;
+        }
+        self.second_revisions = ApplicationContext::load_revisions(&self.second_file);
+        if self.second_revisions.is_empty() {
+            self.second_revision = Revision::WorkingTree;
+        }
+        self.has_revisions = true;
+    }
+
+    /// Check whether the text areas contain edits that were not saved
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let is_dirty: bool = is_dirty()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if the first or the second text area is dirty
+    fn is_dirty(&self) -> bool {
+        self.first_text.is_dirty() || self.second_text.is_dirty()
+    }
+
+    /// Get the settings of the comparison that are restored when the application is started again
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let tab: TabSettings = settings()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `TabSettings` of the comparison
+    fn settings(&self) -> TabSettings {
+        let (first_text, second_text) = match self.input_mode {
+            InputMode::Files => (vec![], vec![]),
+            InputMode::Text => (
+                self.first_text.lines().to_vec(),
+                self.second_text.lines().to_vec(),
+            ),
+        };
+
+        TabSettings {
+            input_mode: self.input_mode,
+            first_file: self.first_file.clone(),
+            second_file: self.second_file.clone(),
+            first_revision: revision_name(&self.first_revision),
+            second_revision: revision_name(&self.second_revision),
+            first_text,
+            second_text,
+            compare_mode: self.compare_mode,
+            ignore_rules: self.ignore_rules.clone(),
+            view_mode: self.view_mode,
+            filter: self.filter.clone(),
+            has_compared: self.has_compared || self.pending_compare,
+        }
+    }
+
+    /// Set the path of the first file and load its git revisions
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// set_first_file(String::from("/path/to/file.txt"))
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the first file
+    fn set_first_file(&mut self, path: String) {
+        self.first_revisions = ApplicationContext::load_revisions(&path);
+        if self.first_revisions.is_empty() {
+            self.first_revision = Revision::WorkingTree;
+        }
+        self.first_file = path;
+    }
+
+    /// Set the path of the second file and load its git revisions
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// set_second_file(String::from("/path/to/file.txt"))
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the second file
+    fn set_second_file(&mut self, path: String) {
+        self.second_revisions = ApplicationContext::load_revisions(&path);
+        if self.second_revisions.is_empty() {
+            self.second_revision = Revision::WorkingTree;
+        }
+        self.second_file = path;
+    }
+
+    /// Calculate the differences between the lines of the first and second text area
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let res: Result<(), Error> = rediff()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Nothing if the differences were calculated or an `Error` if an ignore rule is invalid
+    fn rediff(&mut self) -> Result<(), Error> {
+        let lines_first_text = self.ignore_rules.apply(self.first_text.lines().to_vec())?;
+        let lines_second_text = self.ignore_rules.apply(self.second_text.lines().to_vec())?;
+        let ignored = (
+            self.ignore_rules.matches(self.first_text.lines())?,
+            self.ignore_rules.matches(self.second_text.lines())?,
+        );
+
+        let first = self.first_text.lines();
+        let second = self.second_text.lines();
+
+        // Hunks that only consist of ignored lines are not shown
+        let hunks: Vec<Hunk> = diff::hunks(&diff::diff(first, second))
+            .into_iter()
+            .filter(|h| {
+                !(ignored.0[h.old_index..h.old_index + h.old_len]
+                    .iter()
+                    .chain(ignored.1[h.new_index..h.new_index + h.new_len].iter())
+                    .all(|d| *d))
+            })
+            .collect();
+        self.side_by_side.set_hunks(hunks);
+
+        // The ignored lines were left out, so the positions are mapped back to the text areas
+        let kept = |ignored: &[bool]| -> Vec<usize> {
+            (0..ignored.len()).filter(|i| !ignored[*i]).collect()
+        };
+        let (kept_first, kept_second) = (kept(&ignored.0), kept(&ignored.1));
+        self.differences = self
+            .compare_mode
+            .get_located_differences(lines_first_text, lines_second_text, self.language)
+            .into_iter()
+            .map(|mut d| {
+                let lines = match d.origin {
+                    Origin::First => &kept_first,
+                    Origin::Second => &kept_second,
+                };
+                d.line = lines.get(d.line).copied().unwrap_or(d.line);
+                d
+            })
+            .collect();
+        self.statistics = Some(self.calculate_statistics());
+        self.is_folder_comparison = false;
+        self.has_compared = true;
+        self.apply_filter();
+        Ok(())
+    }
+
+    /// Calculate the statistics of the differences
+    ///
+    /// When comparing tokens, the hunks that only differ in comments or formatting are not
+    /// counted, so that the statistics match the list of differences.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let statistics: Statistics = calculate_statistics();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Statistics` of the differences, with the exact sizes of both texts
+    fn calculate_statistics(&self) -> Statistics {
+        let hunks: Vec<Hunk> = match self.compare_mode {
+            CompareMode::Token => self
+                .side_by_side
+                .hunks
+                .iter()
+                .filter(|h| {
+                    self.differences.iter().any(|d| match d.origin {
+                        Origin::First => (h.old_index..h.old_index + h.old_len).contains(&d.line),
+                        Origin::Second => (h.new_index..h.new_index + h.new_len).contains(&d.line),
+                    })
+                })
+                .copied()
+                .collect(),
+            _ => self.side_by_side.hunks.clone(),
+        };
+
+        Statistics::from_hunks(self.first_text.lines(), self.second_text.lines(), &hunks)
+            .with_bytes(self.first_text.byte_len(), self.second_text.byte_len())
+    }
+
+    /// Decide which changes are shown, based on the active filter
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// apply_filter()
+    /// ```
+    fn apply_filter(&mut self) {
+        let regex = if self.filter.pattern.is_empty() {
+            self.filter_error = None;
+            None
+        } else {
+            match Regex::new(&self.filter.pattern) {
+                Ok(d) => {
+                    self.filter_error = None;
+                    Some(d)
+                }
+                Err(e) => {
+                    self.filter_error = Some(e.to_string());
+                    None
+                }
+            }
+        };
+
+        let first = self.first_text.lines();
+        let second = self.second_text.lines();
+        let hunks = &self.side_by_side.hunks;
+        let hunk_lines = |h: &Hunk| {
+            (
+                first
+                    .get(h.old_index..h.old_index + h.old_len)
+                    .unwrap_or_default(),
+                second
+                    .get(h.new_index..h.new_index + h.new_len)
+                    .unwrap_or_default(),
+            )
+        };
+
+        let whitespace_only: Vec<bool> = hunks
+            .iter()
+            .map(|h| {
+                let (old, new) = hunk_lines(h);
+                let strip = |lines: &[String]| -> String {
+                    lines
+                        .iter()
+                        .flat_map(|l| l.chars())
+                        .filter(|c| !c.is_whitespace())
+                        .collect()
+                };
+                strip(old) == strip(new)
+            })
+            .collect();
+
+        let visible = hunks
+            .iter()
+            .zip(whitespace_only.iter())
+            .map(|(h, w)| {
+                let (old, new) = hunk_lines(h);
+                let lines: Vec<&String> = old.iter().chain(new.iter()).collect();
+                self.filter
+                    .accepts(SideFilter::of(h), *w, &lines, regex.as_ref())
+            })
+            .collect();
+
+        // The entries of the list belong to the hunk that contains their line
+        let mut first_hunks = vec![None; first.len()];
+        let mut second_hunks = vec![None; second.len()];
+        for (index, h) in hunks.iter().enumerate() {
+            for line in first_hunks.iter_mut().skip(h.old_index).take(h.old_len) {
+                *line = Some(index);
+            }
+            for line in second_hunks.iter_mut().skip(h.new_index).take(h.new_len) {
+                *line = Some(index);
+            }
+        }
+
+        self.visible_differences = self
+            .differences
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| {
+                let (line_hunks, kind) = match d.origin {
+                    Origin::First => (&first_hunks, SideFilter::FirstOnly),
+                    Origin::Second => (&second_hunks, SideFilter::SecondOnly),
+                };
+                match line_hunks.get(d.line).copied().flatten() {
+                    Some(i) => self.filter.accepts(
+                        SideFilter::of(&hunks[i]),
+                        whitespace_only[i],
+                        &[&d.text],
+                        regex.as_ref(),
+                    ),
+                    None => self.filter.accepts(
+                        kind,
+                        d.text.trim().is_empty(),
+                        &[&d.text],
+                        regex.as_ref(),
+                    ),
+                }
+            })
+            .map(|(i, _)| i)
+            .collect();
+        self.side_by_side.visible = visible;
+        self.side_by_side.layout(first.len(), second.len());
+
+        if self.current_change >= Some(self.change_count()) {
+            self.current_change = None;
+        }
+    }
+
+    /// Get the changes that are shown in the current view
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let changes: Vec<usize> = visible_changes()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The indices of the visible hunks in the side-by-side view or of the visible differences in
+    /// the list
+    fn visible_changes(&self) -> Vec<usize> {
+        if !self.has_compared || self.is_folder_comparison {
+            return vec![];
+        }
+
+        match self.view_mode {
+            ViewMode::List => self.visible_differences.clone(),
+            ViewMode::SideBySide => (0..self.side_by_side.hunks.len())
+                .filter(|i| self.side_by_side.visible.get(*i).copied().unwrap_or(true))
+                .collect(),
+        }
+    }
+
+    /// Get the location of a range of lines, such as `path/to/file.txt:12-18`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let location: String = location(line_range)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of lines
+    ///
+    /// # Returns
+    ///
+    /// The path of the file, or the title of the text, and the line numbers of the range
+    fn location(&self, range: LineRange) -> String {
+        let name = match (self.input_mode, range.side) {
+            (InputMode::Files, Side::First) => self.first_file.as_str(),
+            (InputMode::Files, Side::Second) => self.second_file.as_str(),
+            (InputMode::Text, Side::First) => "First text",
+            (InputMode::Text, Side::Second) => "Second text",
+        };
+
+        if range.start == range.end {
+            format!("{}:{}", name, range.start + 1)
+        } else {
+            format!("{}:{}-{}", name, range.start + 1, range.end + 1)
+        }
+    }
+
+    /// Get the locations of the visible changes in the compared files, for the overview ruler
+    ///
+    /// The lines of both files are scaled to the length of the longer file, so that every change
+    /// is marked where it is located in its file, no matter how many rows are folded or filtered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let (locations, len): (Vec<(usize, usize)>, usize) = overview_locations()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The scaled line and the position among the visible changes of every change, ordered by
+    /// line, and the length of the longer file
+    fn overview_locations(&self) -> (Vec<(usize, usize)>, usize) {
+        let first_len = self.first_text.lines().len();
+        let second_len = self.second_text.lines().len();
+        let len = usize::max(first_len, second_len);
+        let scale = |line: usize, file_len: usize| match file_len {
+            0 => 0,
+            _ => line * len / file_len,
+        };
+
+        let mut locations: Vec<(usize, usize)> = self
+            .visible_changes()
+            .into_iter()
+            .enumerate()
+            .map(|(position, index)| {
+                let line = match self.view_mode {
+                    ViewMode::List => {
+                        let difference = &self.differences[index];
+                        match difference.origin {
+                            Origin::First => scale(difference.line, first_len),
+                            Origin::Second => scale(difference.line, second_len),
+                        }
+                    }
+                    ViewMode::SideBySide => {
+                        let hunk = &self.side_by_side.hunks[index];
+                        if hunk.old_len > 0 {
+                            scale(hunk.old_index, first_len)
+                        } else {
+                            scale(hunk.new_index, second_len)
+                        }
+                    }
+                };
+                (line, position)
+            })
+            .collect();
+        locations.sort_unstable();
+
+        (locations, len)
+    }
+
+    /// Get the lines that contain a change, for the overview ruler
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let (marks, len): (Vec<(usize, Color)>, usize) = overview_marks(theme)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The `Theme` that contains the colors of the changes
+    ///
+    /// # Returns
+    ///
+    /// The scaled lines that contain a change with the `Color` of the change and the length of the
+    /// longer file
+    fn overview_marks(&self, theme: style::Theme) -> (Vec<(usize, Color)>, usize) {
+        let colors = theme.diff_colors();
+        let changes = self.visible_changes();
+        let (locations, len) = self.overview_locations();
+
+        let marks = locations
+            .into_iter()
+            .map(|(line, position)| {
+                let color = match self.view_mode {
+                    ViewMode::List => colors.changed.0,
+                    ViewMode::SideBySide => {
+                        match SideFilter::of(&self.side_by_side.hunks[changes[position]]) {
+                            SideFilter::FirstOnly => colors.removed.0,
+                            SideFilter::SecondOnly => colors.added.0,
+                            _ => colors.changed.0,
+                        }
+                    }
+                };
+                (line, color)
+            })
+            .collect();
+
+        (marks, len)
+    }
+
+    /// Go to the location of the overview ruler that was pressed
+    ///
+    /// A change at the line is selected. Otherwise the list of differences selects the next
+    /// change and the side-by-side view scrolls to the row of the line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// go_to_overview(120)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line, scaled to the length of the longer file
+    fn go_to_overview(&mut self, line: usize) {
+        let (locations, len) = self.overview_locations();
+        if let Some((_, position)) = locations.iter().find(|(d, _)| *d == line) {
+            return self.select_change(*position);
+        }
+
+        match self.view_mode {
+            ViewMode::List => {
+                let next = locations
+                    .iter()
+                    .find(|(d, _)| *d > line)
+                    .or(locations.last());
+                if let Some((_, position)) = next {
+                    self.select_change(*position);
+                }
+            }
+            ViewMode::SideBySide => {
+                let first_len = self.first_text.lines().len();
+                self.side_by_side
+                    .scroll_to_line(line * first_len / usize::max(len, 1));
+            }
+        }
+    }
+
+    /// Get the number of changes in the current view
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let count: usize = change_count()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The number of visible hunks in the side-by-side view or visible differences in the list
+    fn change_count(&self) -> usize {
+        self.visible_changes().len()
+    }
+
+    /// Select the next or previous change and scroll to it
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// go_to_change(true)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - Whether the next or the previous change should be selected
+    fn go_to_change(&mut self, forward: bool) {
+        let count = self.change_count();
+        if count == 0 {
+            self.current_change = None;
+            return;
+        }
+
+        let position = match (self.current_change, forward) {
+            (Some(d), true) => usize::min(d + 1, count - 1),
+            (Some(d), false) => d.saturating_sub(1),
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+        self.select_change(position);
+    }
+
+    /// Select the next or previous change that matches the search and scroll to it
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// go_to_match(true)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - Whether the next or the previous match should be selected
+    fn go_to_match(&mut self, forward: bool) {
+        let matches = self.search_matches();
+        let position = match (self.current_change, forward) {
+            (Some(d), true) => matches.iter().find(|m| **m > d).or(matches.first()),
+            (Some(d), false) => matches.iter().rev().find(|m| **m < d).or(matches.last()),
+            (None, true) => matches.first(),
+            (None, false) => matches.last(),
+        };
+
+        if let Some(&d) = position {
+            self.select_change(d);
+        }
+    }
+
+    /// Get the changes that contain the search text
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let matches: Vec<usize> = search_matches()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The positions of the matching changes among the visible changes
+    fn search_matches(&self) -> Vec<usize> {
+        if self.search.is_empty() {
+            return vec![];
+        }
+
+        let first = self.first_text.lines();
+        let second = self.second_text.lines();
+        let contains = |line: &String| !find_matches(line, &self.search).is_empty();
+
+        self.visible_changes()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, index)| match self.view_mode {
+                ViewMode::List => self
+                    .differences
+                    .get(*index)
+                    .is_some_and(|d| contains(&d.text)),
+                ViewMode::SideBySide => {
+                    let hunk = &self.side_by_side.hunks[*index];
+                    let old = first
+                        .get(hunk.old_index..hunk.old_index + hunk.old_len)
+                        .unwrap_or_default();
+                    let new = second
+                        .get(hunk.new_index..hunk.new_index + hunk.new_len)
+                        .unwrap_or_default();
+                    old.iter().chain(new.iter()).any(contains)
+                }
+            })
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Select a change and scroll to it
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// select_change(2)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the change among the visible changes
+    fn select_change(&mut self, position: usize) {
+        let changes = self.visible_changes();
+        let index = match changes.get(position) {
+            Some(d) => *d,
+            None => return,
+        };
+        self.current_change = Some(position);
+
+        match self.view_mode {
+            ViewMode::List => self.differences_list.scroll_to(position),
+            ViewMode::SideBySide => self.side_by_side.scroll_to(index),
+        }
+    }
+
+    /// Calculate the differences again once the text areas were not edited for a while
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// schedule_rediff()
+    /// ```
+    fn schedule_rediff(&mut self) {
+        if !self.has_compared {
+            return;
+        }
+
+        // Every edit moves the deadline, so that only the last edit of a burst is compared
+        self.rediff_at = Some(Instant::now() + REDIFF_DELAY);
+        self.side_by_side.is_outdated = true;
+    }
+
+    /// Get the files of the comparison that are watched for changes on disk
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let paths: Vec<String> = watched_files()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The paths of the compared files that are read from the working tree
+    fn watched_files(&self) -> Vec<String> {
+        if !self.has_compared || self.is_folder_comparison || self.input_mode != InputMode::Files {
+            return vec![];
+        }
+
+        [
+            (&self.first_file, &self.first_revision),
+            (&self.second_file, &self.second_revision),
+        ]
+        .into_iter()
+        .filter(|(path, revision)| !path.is_empty() && **revision == Revision::WorkingTree)
+        .map(|(path, _)| path.clone())
+        .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct ApplicationContext {
+    pub theme: style::Theme,
+    pub first_file_input: text_input::State,
+    pub second_file_input: text_input::State,
+    pub first_revision_picker: pick_list::State<Revision>,
+    pub second_revision_picker: pick_list::State<Revision>,
+    pub btn_select_first_file: button::State,
+    pub btn_select_second_file: button::State,
+    pub btn_compare: button::State,
+    pub btn_clean_compare: button::State,
+    pub btn_export: button::State,
+    pub scrollable: scrollable::State,
+    pub merge: Option<MergeContext>,
+    pub notifications: Notifications,
+    pub native_dialogs: bool,
+    pub ignore_rule: String,
+    pub ignore_rule_input: text_input::State,
+    pub recent_picker: pick_list::State<RecentPair>,
+    pub settings: Settings,
+    pub dropped_file: Option<(String, Instant)>,
+    pub context_picker: pick_list::State<usize>,
+    pub overview_ruler: OverviewRuler,
+    pub side_filter_picker: pick_list::State<SideFilter>,
+    pub filter_pattern_input: text_input::State,
+    pub search_input: text_input::State,
+    pub btn_previous_match: button::State,
+    pub btn_next_match: button::State,
+    pub btn_copy_location: button::State,
+    pub btn_previous_change: button::State,
+    pub btn_next_change: button::State,
+    pub tabs: Vec<Comparison>,
+    pub active_tab: usize,
+    pub btn_tabs: Vec<[button::State; 2]>,
+    pub btn_new_tab: button::State,
+    pub btn_open_session: button::State,
+    pub btn_save_session: button::State,
+    pub restores_tabs: bool,
+    pub pending_action: Option<Message>,
+    pub discard_confirmed: bool,
+    pub btn_save_edits: button::State,
+    pub btn_discard_edits: button::State,
+    pub btn_cancel_action: button::State,
+    pub should_exit: bool,
+}
+
+impl ApplicationContext {
+    /// Notify the user in the notification area of the window
+    ///
+    /// When native dialogs are enabled, a native alert is shown instead. The notification area is
+    /// used as a fallback when the alert cannot be shown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// notify(Severity::Info, "hello")
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - The `Severity` of the notification
+    /// * `content` - the content of the notification
+    fn notify(&mut self, severity: Severity, content: &str) {
+        if self.native_dialogs {
+            let message_type = match severity {
+                Severity::Info => MessageType::Info,
+                Severity::Warning => MessageType::Warning,
+                Severity::Error => MessageType::Error,
+            };
+
+            let res = MessageDialog::new()
+                .set_type(message_type)
+                .set_title("text-diff")
+                .set_text(content)
+                .show_alert();
+
+            if res.is_ok() {
+                return;
+            }
+        }
+
+        self.notifications.push(severity, content);
+    }
+
+    /// Display an `Error` to the user
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// display_error(&error)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The `Error` that should be displayed
+    fn display_error(&mut self, error: &Error) {
+        ApplicationContext::notify(self, Severity::Error, &error.to_string());
+    }
+
+    /// Store the settings in the settings file
+    ///
+    /// Options that were passed on the command-line are only stored once they are changed in the
+    /// window. The tabs are only stored when they were restored on startup, so that comparing
+    /// files from the command-line does not replace them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// save_settings()
+    /// ```
+    fn save_settings(&mut self) {
+        if self.restores_tabs {
+            self.settings.tabs = self.tabs.iter().map(Comparison::settings).collect();
+            self.settings.active_tab = self.active_tab;
+        }
+
+        if let Err(e) = self.settings.save() {
+            ApplicationContext::display_error(self, &e);
+        }
+    }
+
+    /// Get the comparison of the selected tab
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let tab: &Comparison = tab()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Comparison` of the selected tab
+    fn tab(&self) -> &Comparison {
+        &self.tabs[self.active_tab]
+    }
+
+    /// Get the comparison of the selected tab to change it
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let tab: &mut Comparison = tab_mut()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Comparison` of the selected tab
+    fn tab_mut(&mut self) -> &mut Comparison {
+        &mut self.tabs[self.active_tab]
+    }
+
+    /// Select a tab
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let should_compare: bool = select_tab(1)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the tab
+    ///
+    /// # Returns
+    ///
+    /// `true` if the files of the tab were restored and should be compared
+    fn select_tab(&mut self, index: usize) -> bool {
+        if index == self.active_tab || index >= self.tabs.len() {
+            return false;
+        }
+
+        self.active_tab = index;
+        self.tabs[index].load_revisions();
+        std::mem::take(&mut self.tabs[index].pending_compare)
+    }
+
+    /// Ask the user to save or discard the unsaved edits of a tab before an action discards them
+    ///
+    /// The tab is selected, so that its edits can be saved. The action is performed again once
+    /// the user saved or discarded the edits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let can_continue: bool = confirm_discard(0, Message::ClearComparePressed)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the tab whose edits would be discarded
+    /// * `action` - The `Message` of the action
+    ///
+    /// # Returns
+    ///
+    /// `true` if the action can continue, because the tab has no unsaved edits or the user chose
+    /// to discard them
+    fn confirm_discard(&mut self, index: usize, action: Message) -> bool {
+        if self.discard_confirmed || !self.tabs.get(index).is_some_and(Comparison::is_dirty) {
+            return true;
+        }
+
+        self.select_tab(index);
+        self.pending_action = Some(action);
+        false
+    }
+
+    /// Open a new tab and select it
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// new_tab()
+    /// ```
+    fn new_tab(&mut self) {
+        self.tabs.push(Comparison::new(&self.settings));
+        self.select_tab(self.tabs.len() - 1);
+    }
+
+    /// Close a tab
+    ///
+    /// The last remaining tab is replaced by a new tab instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let should_compare: bool = close_tab(1)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the tab
+    ///
+    /// # Returns
+    ///
+    /// `true` if the tab that is selected instead was restored and should be compared
+    fn close_tab(&mut self, index: usize) -> bool {
+        if index >= self.tabs.len() {
+            return false;
+        }
+
+        if self.tabs.len() == 1 {
+            self.tabs[0] = Comparison::new(&self.settings);
+            return false;
+        }
+
+        let mut should_compare = false;
+        if index == self.active_tab {
+            let next = if index + 1 < self.tabs.len() {
+                index + 1
+            } else {
+                index - 1
+            };
+            should_compare = self.select_tab(next);
+        }
+
+        self.tabs.remove(index);
+        if self.active_tab > index {
+            self.active_tab -= 1;
+        }
+
+        should_compare
+    }
+
+    /// Capture the comparison of the selected tab in a `Session`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let session: Session = session()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Session` with the inputs, options, filters and scroll position of the comparison
+    fn session(&self) -> Session {
+        let tab = self.tab();
+        Session {
+            tab: tab.settings(),
+            context_lines: tab.side_by_side.context,
+            search: tab.search.clone(),
+            current_change: tab.current_change,
+            scroll_position: match tab.view_mode {
+                ViewMode::List => tab.differences_list.first_row(),
+                ViewMode::SideBySide => tab.side_by_side.list.first_row(),
+            },
+            ..Session::default()
+        }
+    }
+
+    /// Open a `Session` in the selected tab and compare it again
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let command: Command<Message> = open_session(session)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The `Session` that is opened
+    ///
+    /// # Returns
+    ///
+    /// The `Command` of the comparison
+    fn open_session(&mut self, session: Session) -> Command<Message> {
+        let mut tab = Comparison::restore(&session.tab, &self.settings);
+        tab.side_by_side.context = session.context_lines;
+        tab.search = session.search;
+        tab.pending_compare = false;
+        tab.load_revisions();
+        *self.tab_mut() = tab;
+
+        if !session.tab.has_compared {
+            return Command::none();
+        }
+
+        let command = self.update(Message::ComparePressed);
+        let tab = self.tab_mut();
+        if tab.has_compared {
+            if session.current_change < Some(tab.change_count()) {
+                tab.current_change = session.current_change;
+            }
+            match tab.view_mode {
+                ViewMode::List => tab.differences_list.scroll_to(session.scroll_position),
+                ViewMode::SideBySide => tab.side_by_side.list.scroll_to(session.scroll_position),
+            }
+        }
+
+        command
+    }
+
+    /// Get the titles of the tabs
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let titles: Vec<String> = tab_titles()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` that contains the title of every tab
+    fn tab_titles(&self) -> Vec<String> {
+        self.tabs
+            .iter()
+            .map(|t| tab_title(t.input_mode, &t.first_file, &t.second_file))
+            .collect()
+    }
+
+    /// Let the user select the first or second file of the selected tab
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let is_selected: bool = select_file(Side::First)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The `Side` of the file
+    ///
+    /// # Returns
+    ///
+    /// `true` if a file was selected or `false` if the dialog was cancelled or failed
+    fn select_file(&mut self, side: Side) -> bool {
+        let path = match ApplicationContext::open_file_dialog(&[
+            ("Text file", &["txt"]),
+            ("All files", &["*"]),
+        ]) {
+            Ok(res) => match res {
+                Some(d) => d,
+                None => return false,
+            },
+            Err(e) => {
+                ApplicationContext::display_error(self, &e);
+                return false;
+            }
+        };
+
+        match side {
+            Side::First => self.tab_mut().set_first_file(path),
+            Side::Second => self.tab_mut().set_second_file(path),
+        }
+        true
+    }
+
+    /// Open the file dialog to select a file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let selected_file_result: Result<Option<String>, Error> = open_file_dialog(&[("Text file", &["txt"])])
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - The names and extensions of the file types that can be selected
+    ///
+    /// # Returns
+    ///
+    /// The optional `String` that contains the path of the selected file or an `Error`
+    fn open_file_dialog(filters: &[(&str, &[&str])]) -> Result<Option<String>, Error> {
+        let path = filters
+            .iter()
+            .fold(FileDialog::new(), |dialog, (name, extensions)| {
+                dialog.add_filter(name, extensions)
+            })
+            .show_open_single_file();
+
+        match path {
+            Ok(d) => path_to_string(d),
+            Err(e) => Err(Error::Dialog {
+                operation: "open file",
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    /// Open the file dialog to select the file that should be written
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let selected_file_result: Result<Option<String>, Error> = save_file_dialog(&[("Text file", &["txt"])])
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - The names and extensions of the file types that can be selected
+    ///
+    /// # Returns
+    ///
+    /// The optional `String` that contains the path of the selected file or an `Error`
+    fn save_file_dialog(filters: &[(&str, &[&str])]) -> Result<Option<String>, Error> {
+        let path = filters
+            .iter()
+            .fold(FileDialog::new(), |dialog, (name, extensions)| {
+                dialog.add_filter(name, extensions)
+            })
+            .show_save_single_file();
+
+        match path {
+            Ok(d) => path_to_string(d),
+            Err(e) => Err(Error::Dialog {
+                operation: "save file",
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    /// Get the git revisions of a file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let revisions: Vec<Revision> = load_revisions("/path/to/file.txt")
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file
+    ///
+    /// # Returns
+    ///
+    /// The `Vec` that contains the revisions of the file or an empty `Vec` if the file is not
+    /// tracked in a git repository
+    fn load_revisions(path: &str) -> Vec<Revision> {
+        if path.is_empty() || Path::new(path).is_dir() {
+            return vec![];
+        }
+
+        FileReader::new().list_revisions(path).unwrap_or_default()
+    }
+
+    /// Compare the files in the first and second folder
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// compare_folders()
+    /// ```
+    fn compare_folders(&mut self) {
+        let tab = &mut self.tabs[self.active_tab];
+        let entries = match FolderComparer::new(&tab.first_file, &tab.second_file).compare() {
+            Ok(d) => d,
+            Err(e) => {
+                ApplicationContext::display_error(self, &e);
+                return;
+            }
+        };
+
+        tab.btn_folder_entries = entries.iter().map(|_| button::State::default()).collect();
+        tab.folder_entries = entries;
+        tab.is_folder_comparison = true;
+        tab.differences = vec![];
+        tab.statistics = None;
+        tab.has_compared = true;
+
+        self.settings.add_recent(&tab.first_file, &tab.second_file);
+        self.save_settings();
+    }
+
+    /// Compare the lines of the first and second text area
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// compare_texts()
+    /// ```
+    fn compare_texts(&mut self) {
+        let tab = self.tab_mut();
+        if tab.first_text.is_empty() && tab.second_text.is_empty() {
+            ApplicationContext::notify(self, Severity::Warning, "Please enter some text first!");
+            return;
+        }
+
+        tab.language = Language::PlainText;
+        self.rediff(self.active_tab);
+    }
+
+    /// Calculate the differences of a tab again and display the error if it fails
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let res: bool = rediff(0)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the tab
+    ///
+    /// # Returns
+    ///
+    /// `true` if the differences were calculated or `false` if an ignore rule is invalid
+    fn rediff(&mut self, index: usize) -> bool {
+        match self.tabs[index].rediff() {
+            Ok(_) => true,
+            Err(e) => {
+                ApplicationContext::display_error(self, &e);
+                false
+            }
+        }
+    }
+
+    /// Get the files of the selected tab that are watched for changes on disk
+    ///
+    /// No files are watched during a three-way merge.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let paths: Vec<String> = watched_files()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The paths of the compared files that are read from the working tree
+    fn watched_files(&self) -> Vec<String> {
+        if self.merge.is_some() {
+            return vec![];
+        }
+
+        self.tab().watched_files()
+    }
+
+    /// Read the compared files of a tab again and update the differences
+    ///
+    /// Files with unsaved edits are not reloaded, so that the edits are not lost. The scroll
+    /// position and the selected change are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// reload_files(0)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the tab
+    fn reload_files(&mut self, index: usize) {
+        let file_reader = FileReader::new();
+        let tab = &self.tabs[index];
+        let watched_files = tab.watched_files();
+        let sides = [
+            (Side::First, tab.first_file.clone()),
+            (Side::Second, tab.second_file.clone()),
+        ];
+
+        let mut changed = false;
+        for (side, path) in sides {
+            if !watched_files.contains(&path) {
+                continue;
+            }
+
+            let tab = &mut self.tabs[index];
+            let is_dirty = match side {
+                Side::First => tab.first_text.is_dirty(),
+                Side::Second => tab.second_text.is_dirty(),
+            };
+            if is_dirty {
+                ApplicationContext::notify(
+                    self,
+                    Severity::Warning,
+                    &format!(
+                        "{} was changed on disk, but it is not reloaded because of unsaved edits",
+                        path
+                    ),
+                );
+                continue;
+            }
+
+            let (lines, format) = match file_reader
+                .read_source_with_format(&FileSource::new(&path, Revision::WorkingTree))
+            {
+                Ok(d) => d,
+                Err(e) => {
+                    ApplicationContext::display_error(self, &e);
+                    continue;
+                }
+            };
+
+            let tab = &mut self.tabs[index];
+            let text_area = match side {
+                Side::First => &mut tab.first_text,
+                Side::Second => &mut tab.second_text,
+            };
+            if text_area.text() != format.join(&lines) {
+                text_area.set_lines(lines, format);
+                changed = true;
+            }
+        }
+
+        if changed {
+            self.rediff(index);
+        }
+    }
+
+    /// Save the lines of a text area to a file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// save_text(Side::First)
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The `Side` of the text area that should be saved
+    fn save_text(&mut self, side: Side) {
+        let tab = self.tab();
+        let (path, revision) = match side {
+            Side::First => (&tab.first_file, &tab.first_revision),
+            Side::Second => (&tab.second_file, &tab.second_revision),
+        };
+
+        // Edits of a file are saved back to it, unless an older revision of it is shown
+        let path = if tab.input_mode == InputMode::Files
+            && !path.is_empty()
+            && *revision == Revision::WorkingTree
+        {
+            path.clone()
+        } else {
+            match ApplicationContext::save_file_dialog(&[
+                ("Text file", &["txt"]),
+                ("All files", &["*"]),
+            ]) {
+                Ok(res) => match res {
+                    Some(d) => d,
+                    None => return,
+                },
+                Err(e) => {
+                    ApplicationContext::display_error(self, &e);
+                    return;
+                }
+            }
+        };
+
+        let tab = self.tab_mut();
+        let text_area = match side {
+            Side::First => &mut tab.first_text,
+            Side::Second => &mut tab.second_text,
+        };
+
+        match std::fs::write(&path, text_area.text()) {
+            Ok(_) => {
+                text_area.mark_saved();
+                ApplicationContext::notify(
+                    self,
+                    Severity::Info,
+                    &format!("Saved the text to {}", path),
+                )
+            }
+            Err(e) => ApplicationContext::display_error(self, &Error::Write { path, source: e }),
+        }
+    }
+
+    /// Start a three-way merge of the first and second file into the merged file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// start_merge("/path/to/base.txt", "/path/to/merged.txt")
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `base_file` - The path of the common ancestor of the first and second file
+    /// * `merged_file` - The path of the file that the merged result should be written to
+    fn start_merge(&mut self, base_file: &str, merged_file: &str) {
+        // Until the merged result is saved, git should consider the merge unsuccessful
+        EXIT_CODE.store(1, Ordering::SeqCst);
+
+        let file_reader = FileReader::new();
+        let mut contents = vec![];
+        let paths = [
+            String::from(base_file),
+            self.tab().first_file.clone(),
+            self.tab().second_file.clone(),
+        ];
+        for path in &paths {
+            match file_reader.read_lines_with_format(path) {
+                Ok(d) => contents.push(d),
+                Err(e) => {
+                    ApplicationContext::display_error(self, &e);
+                    return;
+                }
+            };
+        }
+
+        let (remote, _) = contents.pop().unwrap_or_default();
+        let (local, format) = contents.pop().unwrap_or_default();
+        let (base, _) = contents.pop().unwrap_or_default();
+        let chunks = ThreeWayMerger::new(base, local, remote).merge();
+
+        self.tab_mut().language = Language::from_path(merged_file);
+        self.merge = Some(MergeContext {
+            merged_file: String::from(merged_file),
+            // The merged result keeps the line endings of the local version
+            format,
+            resolutions: vec![None; chunks.len()],
+            btn_resolutions: chunks.iter().map(|_| Default::default()).collect(),
+            chunks,
+            ..MergeContext::default()
+        });
+    }
+
+    /// Create the view of an ongoing three-way merge
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let element: Element<'_, Message> = view_merge()
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Element` that displays the merge or an empty `Element` if there is no merge
+    fn view_merge(&mut self) -> Element<'_, Message> {
+        let theme = self.theme;
+        let lexer = Lexer::new(self.tab().language);
+        let notifications = self.notifications.view(theme);
+        let merge = match &mut self.merge {
+            Some(d) => d,
+            None => return Column::new().into(),
+        };
+
+        let conflicts = merge
+            .chunks
+            .iter()
+            .filter(|c| matches!(c, MergeChunk::Conflict { .. }))
+            .count();
+        let resolved = merge
+            .chunks
+            .iter()
+            .zip(merge.resolutions.iter())
+            .filter(|(c, r)| matches!(c, MergeChunk::Conflict { .. }) && r.is_some())
+            .count();
+
+        let mut chunk_column = Column::new().spacing(10);
+        for (index, ((chunk, resolution), buttons)) in merge
+            .chunks
+            .iter()
+            .zip(merge.resolutions.iter())
+            .zip(merge.btn_resolutions.iter_mut())
+            .enumerate()
+        {
+            match chunk {
+                MergeChunk::Resolved(lines) => {
+                    for line in lines {
+                        chunk_column = chunk_column.push(highlighted_line(
+                            &lexer,
+                            theme,
+                            ("", theme.text_color()),
+                            line,
+                        ));
+                    }
+                }
+                MergeChunk::Conflict { local, remote, .. } => {
+                    let colors = theme.diff_colors();
+                    let mut conflict = Column::new().spacing(5).push(Text::new("Local:").size(16));
+                    for line in local {
+                        conflict = conflict.push(highlighted_line(
+                            &lexer,
+                            theme,
+                            ("< ", colors.removed.0),
+                            line,
+                        ));
+                    }
+                    conflict = conflict.push(Text::new("Remote:").size(16));
+                    for line in remote {
+                        conflict = conflict.push(highlighted_line(
+                            &lexer,
+                            theme,
+                            ("> ", colors.added.0),
+                            line,
+                        ));
+                    }
+
+                    let [btn_local, btn_remote, btn_both] = buttons;
+                    let options = [
+                        (btn_local, "Use local", Resolution::Local),
+                        (btn_remote, "Use remote", Resolution::Remote),
+                        (btn_both, "Use both", Resolution::Both),
+                    ];
+                    let button_row = options.into_iter().fold(
+                        Row::new().spacing(10),
+                        |row, (state, label, option)| {
+                            let label = if *resolution == Some(option) {
+                                format!("✓ {}", label)
+                            } else {
+                                String::from(label)
+                            };
+
+                            row.push(
+                                Button::new(
+                                    state,
+                                    Text::new(label)
+                                        .horizontal_alignment(alignment::Horizontal::Center),
+                                )
+                                .padding(5)
+                                .min_width(100)
+                                .on_press(Message::ResolutionChanged(index, option))
+                                .style(theme),
+                            )
+                        },
+                    );
+
+                    chunk_column = chunk_column
+                        .push(Rule::horizontal(10).style(theme))
+                        .push(conflict.push(button_row))
+                        .push(Rule::horizontal(10).style(theme));
+                }
+            }
+        }
+
+        let scroll = Scrollable::new(&mut merge.scrollable)
+            .push(Container::new(chunk_column).width(Length::Fill))
+            .height(Length::Fill)
+            .style(theme);
+
+        let btn_abort = Button::new(
+            &mut merge.btn_abort,
+            Text::new("Abort").horizontal_alignment(alignment::Horizontal::Center),
+        )
+        .padding(10)
+        .min_width(100)
+        .on_press(Message::AbortMergePressed)
+        .style(theme);
+
+        let mut btn_save = Button::new(
+            &mut merge.btn_save,
+            Text::new("Save").horizontal_alignment(alignment::Horizontal::Center),
+        )
+        .padding(10)
+        .min_width(100)
+        .style(theme);
+
+        if resolved == conflicts {
+            btn_save = btn_save.on_press(Message::SaveMergePressed);
+        }
+
+        let content = Column::new()
+            .spacing(15)
+            .padding(20)
+            .push(notifications)
+            .push(
+                Text::new(format!("Merge into {}", merge.merged_file))
+                    .size(theme.font_sizes().heading),
+            )
+            .push(Text::new(format!(
+                "{} of {} conflicts resolved",
+                resolved, conflicts
+            )))
+            .push(Rule::horizontal(20).style(theme))
+            .push(scroll)
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Column::new()
+                            .width(Length::Fill)
+                            .align_items(Alignment::Start)
+                            .push(btn_abort),
+                    )
+                    .push(
+                        Column::new()
+                            .width(Length::Fill)
+                            .align_items(Alignment::End)
+                            .push(btn_save),
+                    ),
+            );
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(theme)
+            .into()
+    }
+}
+
+/// Create a row of text with syntax highlighting
+///
+/// # Example
+///
+/// ```rust
+/// let row: Row<'_, Message> = highlighted_line(&lexer, theme, ("- ", Color::WHITE), "fn main() {}")
+/// ```
+///
+/// # Arguments
+///
+/// * `lexer` - The `Lexer` that splits the line into tokens
+/// * `theme` - The `Theme` that provides the colors of the tokens
+/// * `prefix` - The text that should be displayed in front of the line and its `Color`
+/// * `line` - The line that should be displayed
+///
+/// # Returns
+///
+/// The `Row` that contains a `Text` for every token in the line
+fn highlighted_line<'a>(
+    lexer: &Lexer,
+    theme: style::Theme,
+    prefix: (&str, Color),
+    line: &str,
+) -> Row<'a, Message> {
+    let (prefix, prefix_color) = prefix;
+    lexer.tokenize(line).into_iter().fold(
+        Row::new().push(Text::new(prefix).color(prefix_color)),
+        |row, token| {
+            let color = theme.syntax_color(token.kind);
+            row.push(Text::new(token.text).color(color))
+        },
+    )
+}
+
+/// Create a row of text in which the matches of a search are highlighted
+///
+/// Lines without a match are highlighted like source code instead.
+///
+/// # Example
+///
+/// ```rust
+/// let row: Row<'_, Message> = searched_line(&lexer, theme, ("- ", Color::WHITE), "fn main() {}", "main")
+/// ```
+///
+/// # Arguments
+///
+/// * `lexer` - The `Lexer` that splits the line into tokens
+/// * `theme` - The `Theme` that provides the colors of the text
+/// * `prefix` - The text that should be displayed in front of the line and its `Color`
+/// * `line` - The line that should be displayed
+/// * `query` - The search text, which is matched regardless of ASCII case
+///
+/// # Returns
+///
+/// The `Row` that contains a `Text` for every match and every part of the line in between
+fn searched_line<'a>(
+    lexer: &Lexer,
+    theme: style::Theme,
+    prefix: (&str, Color),
+    line: &str,
+    query: &str,
+) -> Row<'a, Message> {
+    let matches = find_matches(line, query);
+    if matches.is_empty() {
+        return highlighted_line(lexer, theme, prefix, line);
+    }
+
+    let (prefix, prefix_color) = prefix;
+    let mut row = Row::new().push(Text::new(prefix).color(prefix_color));
+    let mut index = 0;
+    for (start, end) in matches {
+        row = row
+            .push(Text::new(&line[index..start]).color(theme.text_color()))
+            .push(Container::new(Text::new(&line[start..end])).style(style::Highlight(theme)));
+        index = end;
+    }
+
+    row.push(Text::new(&line[index..]).color(theme.text_color()))
+}
+
+/// Find the occurrences of a search text in a line, regardless of ASCII case
+///
+/// # Example
+///
+/// ```rust
+/// let matches: Vec<(usize, usize)> = find_matches("fn main() {}", "main")
+/// ```
+///
+/// # Arguments
+///
+/// * `line` - The line that is searched
+/// * `query` - The search text
+///
+/// # Returns
+///
+/// The `Vec` that contains the start and end of every occurrence
+fn find_matches(line: &str, query: &str) -> Vec<(usize, usize)> {
+    if query.is_empty() {
+        return vec![];
+    }
+
+    // Lowercasing ASCII characters keeps the byte offsets of the line intact
+    line.to_ascii_lowercase()
+        .match_indices(&query.to_ascii_lowercase())
+        .map(|(i, m)| (i, i + m.len()))
+        .collect()
+}
+
+/// Create a row with a line of the first and a line of the second text next to each other
+///
+/// # Example
+///
+/// ```rust
+/// let row: Row<'_, Message> = side_by_side_row(&lexer, theme, prefixes, (Some(&a), None), gutters, "")
+/// ```
+///
+/// # Arguments
+///
+/// * `lexer` - The `Lexer` that splits the lines into tokens
+/// * `theme` - The `Theme` that provides the colors of the tokens
+/// * `prefixes` - The text in front of the first and second line and its `Color`
+/// * `lines` - The line on the left side and the line on the right side, if any
+/// * `gutters` - The line numbers on the left side and on the right side
+/// * `query` - The search text that is highlighted
+///
+/// # Returns
+///
+/// The `Row` that contains both lines
+fn side_by_side_row<'a>(
+    lexer: &Lexer,
+    theme: style::Theme,
+    prefixes: [(&str, Color); 2],
+    lines: (Option<&String>, Option<&String>),
+    gutters: [Element<'a, Message>; 2],
+    query: &str,
+) -> Row<'a, Message> {
+    let [first_prefix, second_prefix] = prefixes;
+    let [first_gutter, second_gutter] = gutters;
+    let cell = |line: Option<&String>, prefix| match line {
+        Some(d) => searched_line(lexer, theme, prefix, d, query).width(Length::Fill),
+        None => Row::new().width(Length::Fill),
+    };
+
+    Row::new()
+        .spacing(10)
+        .push(first_gutter)
+        .push(cell(lines.0, first_prefix))
+        .push(second_gutter)
+        .push(cell(lines.1, second_prefix))
+}
+
+/// Create the line number of a line in the side-by-side view
+///
+/// # Example
+///
+/// ```rust
+/// let element: Element<'_, Message> = gutter(&mut state, Side::First, Some(11), None, theme)
+/// ```
+///
+/// # Arguments
+///
+/// * `state` - The state of the button of the line number
+/// * `side` - The side of the line
+/// * `line` - The index of the line, if the row contains a line on this side
+/// * `selection` - The selected range of lines
+/// * `theme` - The `Theme` of the line number
+///
+/// # Returns
+///
+/// The `Element` that contains the button with the line number, or empty space without a line
+fn gutter<'a>(
+    state: &'a mut button::State,
+    side: Side,
+    line: Option<usize>,
+    selection: Option<LineRange>,
+    theme: style::Theme,
+) -> Element<'a, Message> {
+    let line = match line {
+        Some(d) => d,
+        None => return Space::with_width(Length::Units(GUTTER_WIDTH)).into(),
+    };
+    let selected = selection.is_some_and(|d| d.contains(side, line));
+
+    Button::new(
+        state,
+        Text::new((line + 1).to_string())
+            .width(Length::Fill)
+            .horizontal_alignment(alignment::Horizontal::Right),
+    )
+    .width(Length::Units(GUTTER_WIDTH))
+    .padding(0)
+    .on_press(Message::LineNumberPressed(side, line))
+    .style(style::Gutter(theme, selected))
+    .into()
+}
+
+/// Create the line number of a difference in the list of differences
+///
+/// # Example
+///
+/// ```rust
+/// let element: Element<'_, Message> = line_number(Some(11), theme)
+/// ```
+///
+/// # Arguments
+///
+/// * `line` - The index of the line, if the difference was found on this side
+/// * `theme` - The `Theme` of the line number
+///
+/// # Returns
+///
+/// The `Element` that contains the line number, or empty space without a line
+fn line_number<'a>(line: Option<usize>, theme: style::Theme) -> Element<'a, Message> {
+    match line {
+        Some(d) => Text::new((d + 1).to_string())
+            .width(Length::Units(GUTTER_WIDTH))
+            .horizontal_alignment(alignment::Horizontal::Right)
+            .color(theme.text_color())
+            .into(),
+        None => Space::with_width(Length::Units(GUTTER_WIDTH)).into(),
+    }
+}
+
+/// Create the row that asks the user to save or discard unsaved edits before an action
+///
+/// # Example
+///
+/// ```rust
+/// let row: Container<'_, Message> = unsaved_edits_row([&mut btn_save, &mut btn_discard, &mut btn_cancel], Theme::Dark)
+/// ```
+///
+/// # Arguments
+///
+/// * `states` - The states of the save, discard and cancel buttons
+/// * `theme` - The `Theme` of the buttons
+///
+/// # Returns
+///
+/// The `Container` that contains the question and the buttons
+fn unsaved_edits_row<'a>(
+    states: [&'a mut button::State; 3],
+    theme: style::Theme,
+) -> Container<'a, Message> {
+    let labels = [
+        ("Save", Message::SaveEditsPressed),
+        ("Discard", Message::DiscardEditsPressed),
+        ("Cancel", Message::CancelActionPressed),
+    ];
+
+    let row = states.into_iter().zip(labels).fold(
+        Row::new().spacing(10).align_items(Alignment::Center).push(
+            Text::new("There are unsaved edits. Do you want to save them?").width(Length::Fill),
+        ),
+        |row, (state, (label, message))| {
+            row.push(
+                Button::new(
+                    state,
+                    Text::new(label).horizontal_alignment(alignment::Horizontal::Center),
+                )
+                .padding(5)
+                .min_width(100)
+                .on_press(message)
+                .style(theme),
+            )
+        },
+    );
+
+    Container::new(row)
+        .width(Length::Fill)
+        .padding(10)
+        .style(style::Notification(Severity::Warning))
+}
+
+/// Get the title of a tab
+///
+/// # Example
+///
+/// ```rust
+/// let title: String = tab_title(InputMode::Files, "/path/to/first.txt", "/path/to/second.txt");
+/// ```
+///
+/// # Arguments
+///
+/// * `input_mode` - The `InputMode` of the tab
+/// * `first_file` - The path of the first file
+/// * `second_file` - The path of the second file
+///
+/// # Returns
+///
+/// The names of both files, such as `first.txt ↔ second.txt`
+fn tab_title(input_mode: InputMode, first_file: &str, second_file: &str) -> String {
+    if input_mode == InputMode::Text {
+        return String::from("Text comparison");
+    }
+
+    if first_file.is_empty() && second_file.is_empty() {
+        return String::from("New comparison");
+    }
+
+    let name = |path: &str| {
+        Path::new(path)
+            .file_name()
+            .and_then(OsStr::to_str)
+            .unwrap_or(path)
+            .to_string()
+    };
+    format!("{} ↔ {}", name(first_file), name(second_file))
+}
+
+/// Create the panel that summarizes the statistics of a comparison
+///
+/// The statistics are split into two columns of labels and values.
+///
+/// # Example
+///
+/// ```rust
+/// let panel: Row<'_, Message> = statistics_panel(&statistics);
+/// ```
+///
+/// # Arguments
+///
+/// * `statistics` - The `Statistics` of the comparison
+///
+/// # Returns
+///
+/// The `Row` that contains the columns of the statistics
+fn statistics_panel<'a>(statistics: &Statistics) -> Row<'a, Message> {
+    let entries = statistics.entries();
+    let half = entries.len().div_ceil(2);
+
+    entries
+        .chunks(half)
+        .fold(Row::new().spacing(40), |row, chunk| {
+            let column = chunk.iter().fold(
+                Column::new().spacing(5).width(Length::Fill),
+                |column, (label, value)| {
+                    column.push(
+                        Row::new()
+                            .spacing(10)
+                            .push(Text::new(*label).width(Length::Fill))
+                            .push(Text::new(value.clone())),
+                    )
+                },
+            );
+            row.push(column)
+        })
+}
+
+/// Format a number with a comma between every group of three digits
+///
+/// # Example
+///
+/// ```rust
+/// let text: String = format_count(1204);
+/// ```
+///
+/// # Arguments
+///
+/// * `count` - The number that is formatted
+///
+/// # Returns
+///
+/// The formatted number, such as `1,204`
+fn format_count(count: usize) -> String {
+    let digits = count.to_string();
+    let mut text = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            text.push(',');
+        }
+        text.push(digit);
+    }
+
+    text
+}
+
+/// Convert a path that was selected in a dialog into a `String`
+///
+/// # Example
+///
+/// ```rust
+/// let path: Result<Option<String>, Error> = path_to_string(Some(PathBuf::from("/path/to/file.txt")))
+/// ```
+///
+/// # Arguments
+///
+/// * `path` - The optional path that was selected
+///
+/// # Returns
+///
+/// The optional `String` that contains the path or an `Error` if the path is not valid UTF-8
+fn path_to_string(path: Option<PathBuf>) -> Result<Option<String>, Error> {
+    let path = match path {
+        Some(d) => d,
+        None => return Ok(None),
+    };
+
+    match path.into_os_string().into_string() {
+        Ok(d) => Ok(Some(d)),
+        Err(e) => Err(Error::InvalidPath {
+            path: PathBuf::from(e),
+        }),
+    }
+}
+
+impl Application for ApplicationContext {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = Flags;
+
+    fn new(flags: Flags) -> (Self, Command<Message>) {
+        let Flags {
+            arguments: flags,
+            settings,
+            errors,
+        } = flags;
+        let first_file = flags.first_file.unwrap_or_default();
+        let second_file = flags.second_file.unwrap_or_default();
+
+        let theme = flags.theme.as_deref().and_then(style::Theme::by_name);
+        let mut ignore_rules = settings.ignore_rules.clone();
+        for pattern in flags.ignore_patterns {
+            if !ignore_rules.patterns.contains(&pattern) {
+                ignore_rules.patterns.push(pattern);
+            }
+        }
+
+        let tab = Comparison {
+            compare_mode: flags.compare_mode.unwrap_or(settings.compare_mode),
+            first_revision: flags.first_revision,
+            second_revision: flags.second_revision,
+            first_file,
+            second_file,
+            btn_ignore_rules: ignore_rules
+                .patterns
+                .iter()
+                .map(|_| button::State::default())
+                .collect(),
+            ignore_rules,
+            ..Comparison::new(&settings)
+        };
+        let mut should_compare =
+            !flags.no_compare && !tab.first_file.is_empty() && !tab.second_file.is_empty();
+        let has_files = !tab.first_file.is_empty() || !tab.second_file.is_empty();
+
+        let mut context = ApplicationContext {
+            theme: theme.unwrap_or(settings.theme),
+            native_dialogs: settings.native_dialogs,
+            tabs: vec![tab],
+            settings,
+            ..ApplicationContext::default()
+        };
+
+        for e in errors {
+            ApplicationContext::display_error(&mut context, &e);
+        }
+
+        // The tabs of the last session are restored when no files are passed on the command-line
+        if flags.base_file.is_none() && flags.session_file.is_none() && !has_files {
+            context.restores_tabs = true;
+
+            if !context.settings.tabs.is_empty() {
+                context.tabs = context
+                    .settings
+                    .tabs
+                    .iter()
+                    .map(|d| Comparison::restore(d, &context.settings))
+                    .collect();
+                context.active_tab =
+                    usize::min(context.settings.active_tab, context.tabs.len() - 1);
+                should_compare = std::mem::take(&mut context.tab_mut().pending_compare);
+            }
+        }
+
+        // Only the revisions of the selected tab are loaded, the other tabs load them when they
+        // are selected
+        context.tab_mut().load_revisions();
+
+        let command = match (flags.base_file, flags.merged_file) {
+            (Some(base_file), Some(merged_file)) => {
+                context.start_merge(&base_file, &merged_file);
+                Command::none()
+            }
+            _ if flags.session_file.is_some() => {
+                let path = flags.session_file.unwrap_or_default();
+                match Session::load(&path) {
+                    Ok(d) => context.open_session(d),
+                    Err(e) => {
+                        ApplicationContext::display_error(&mut context, &e);
+                        Command::none()
+                    }
+                }
+            }
+            _ if should_compare => context.update(Message::ComparePressed),
+            _ => Command::none(),
+        };
+
+        (context, command)
+    }
+
+    fn title(&self) -> String {
+        String::from("text-diff")
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Message> {
+        match message {
+            Message::FirstFileInputChanged(d) => self.tab_mut().first_file = d,
+            Message::SecondFileInputChanged(d) => self.tab_mut().second_file = d,
+            Message::FirstFileInputSubmitted => {
+                let tab = self.tab_mut();
+                tab.first_revisions = ApplicationContext::load_revisions(&tab.first_file);
+            }
+            Message::SecondFileInputSubmitted => {
+                let tab = self.tab_mut();
+                tab.second_revisions = ApplicationContext::load_revisions(&tab.second_file);
+            }
+            Message::FirstRevisionChanged(d) => self.tab_mut().first_revision = d,
+            Message::SecondRevisionChanged(d) => self.tab_mut().second_revision = d,
+            Message::SelectFirstFilePressed => {
+                self.select_file(Side::First);
+            }
+            Message::SelectSecondFilePressed => {
+                self.select_file(Side::Second);
+            }
+            Message::ComparePressed => {
+                let tab = self.tab_mut();
+                tab.changed_on_disk = false;
+                if tab.input_mode == InputMode::Text {
+                    self.compare_texts();
+                    return Command::none();
+                }
+
+                if tab.first_file.is_empty() || tab.second_file.is_empty() {
+                    ApplicationContext::notify(
+                        self,
+                        Severity::Warning,
+                        "Please select two files first!",
+                    );
+                    return Command::none();
+                }
+
+                if Path::new(&tab.first_file).is_dir() && Path::new(&tab.second_file).is_dir() {
+                    self.compare_folders();
+                    return Command::none();
+                }
+
+                // Reading the files again replaces the edits of the text areas
+                if !self.confirm_discard(self.active_tab, Message::ComparePressed) {
+                    return Command::none();
+                }
+
+                let file_reader = FileReader::new();
+                let tab = &mut self.tabs[self.active_tab];
+
+                let first_file = file_reader.read_source_with_format(&FileSource::new(
+                    &tab.first_file,
+                    tab.first_revision.clone(),
+                ));
+                let second_file = file_reader.read_source_with_format(&FileSource::new(
+                    &tab.second_file,
+                    tab.second_revision.clone(),
+                ));
+
+                let (lines_first_file, format_first_file) = match first_file {
+                    Ok(d) => d,
+                    Err(e) => {
+                        ApplicationContext::display_error(self, &e);
+                        return Command::none();
+                    }
+                };
+
+                let (lines_second_file, format_second_file) = match second_file {
+                    Ok(d) => d,
+                    Err(e) => {
+                        ApplicationContext::display_error(self, &e);
+                        return Command::none();
+                    }
+                };
+
+                tab.first_text
+                    .set_lines(lines_first_file, format_first_file);
+                tab.second_text
+                    .set_lines(lines_second_file, format_second_file);
+
+                tab.language = match Language::from_path(&tab.first_file) {
+                    Language::PlainText => Language::from_path(&tab.second_file),
+                    d => d,
+                };
+
+                if !self.rediff(self.active_tab) {
+                    return Command::none();
+                }
+
+                let tab = &self.tabs[self.active_tab];
+                self.settings.add_recent(&tab.first_file, &tab.second_file);
+                self.save_settings();
+            }
+            Message::ThemeChanged(d) => {
+                self.theme = d;
+                self.settings.theme = d;
+                self.save_settings();
+            }
+            Message::CompareModeChanged(d) => {
+                self.tab_mut().compare_mode = d;
+                self.settings.compare_mode = d;
+                self.save_settings();
+            }
+            Message::ClearComparePressed => {
+                if !self.confirm_discard(self.active_tab, Message::ClearComparePressed) {
+                    return Command::none();
+                }
+
+                let tab = &mut self.tabs[self.active_tab];
+                tab.first_file = String::new();
+                tab.second_file = String::new();
+                tab.first_revision = Revision::WorkingTree;
+                tab.second_revision = Revision::WorkingTree;
+                tab.first_revisions = vec![];
+                tab.second_revisions = vec![];
+                tab.has_compared = false;
+                tab.differences = vec![];
+                tab.statistics = None;
+                tab.folder_entries = vec![];
+                tab.btn_folder_entries = vec![];
+                tab.is_folder_comparison = false;
+                tab.language = Language::default();
+                tab.first_text = TextArea::default();
+                tab.second_text = TextArea::default();
+                tab.side_by_side = SideBySideContext {
+                    context: self.settings.context_lines,
+                    ..SideBySideContext::default()
+                };
+                tab.differences_list.reset();
+                tab.current_change = None;
+            }
+            Message::ExportPressed => {
+                let path = match ApplicationContext::save_file_dialog(&[
+                    ("Text file", &["txt"]),
+                    ("Csv file", &["csv"]),
+                    ("Json file", &["json"]),
+                ]) {
+                    Ok(res) => match res {
+                        Some(d) => d,
+                        None => return Command::none(),
+                    },
+                    Err(e) => {
+                        ApplicationContext::display_error(self, &e);
+                        return Command::none();
+                    }
+                };
+
+                let extension = match Path::new(&path).extension().and_then(OsStr::to_str) {
+                    Some(x) => x,
+                    None => return Command::none(),
+                };
+
+                let extension = match extension.to_lowercase().as_str() {
+                    "txt" => ExportType::Text,
+                    "csv" => ExportType::Csv,
+                    "json" => ExportType::Json,
+                    _ => ExportType::default(),
+                };
+
+                let tab = self.tab();
+                let differences = if tab.export_filtered {
+                    tab.visible_differences
+                        .iter()
+                        .map(|i| tab.differences[*i].text.clone())
+                        .collect()
+                } else {
+                    tab.differences.iter().map(|d| d.text.clone()).collect()
+                };
+
+                let mut vec_exporter: VectorExporter<String> =
+                    IVectorExporter::<String>::new(differences, extension, &path);
+                if let Some(d) = tab.statistics {
+                    vec_exporter = vec_exporter.with_statistics(d);
+                }
+
+                match vec_exporter.export() {
+                    Ok(_) => ApplicationContext::notify(
+                        self,
+                        Severity::Info,
+                        &format!("Exported the differences to {}", path),
+                    ),
+                    Err(e) => {
+                        ApplicationContext::display_error(self, &Error::Export { path, source: e })
+                    }
+                }
+            }
+            Message::ResolutionChanged(index, resolution) => {
+                if let Some(merge) = &mut self.merge {
+                    if let Some(d) = merge.resolutions.get_mut(index) {
+                        *d = Some(resolution);
+                    }
+                }
+            }
+            Message::SaveMergePressed => {
+                let merge = match &self.merge {
+                    Some(d) => d,
+                    None => return Command::none(),
+                };
+
+                let lines: Vec<String> = merge
+                    .chunks
+                    .iter()
+                    .zip(merge.resolutions.iter())
+                    .flat_map(|(chunk, resolution)| {
+                        chunk.resolve(resolution.unwrap_or(Resolution::Local))
+                    })
+                    .collect();
+
+                match std::fs::write(&merge.merged_file, merge.format.join(&lines)) {
+                    Ok(_) => {
+                        EXIT_CODE.store(0, Ordering::SeqCst);
+                        self.should_exit = true;
+                    }
+                    Err(e) => {
+                        let error = Error::Write {
+                            path: merge.merged_file.clone(),
+                            source: e,
+                        };
+                        ApplicationContext::display_error(self, &error);
+                    }
+                }
+            }
+            Message::AbortMergePressed => {
+                EXIT_CODE.store(1, Ordering::SeqCst);
+                self.should_exit = true;
+            }
+            Message::NativeDialogsToggled(d) => {
+                self.native_dialogs = d;
+                self.settings.native_dialogs = d;
+                self.save_settings();
+            }
+            Message::AutoReloadToggled(d) => {
+                self.settings.auto_reload = d;
+                self.save_settings();
+            }
+            Message::FileChanged(path) => {
+                if !self.watched_files().contains(&path) {
+                    return Command::none();
+                }
+
+                let auto_reload = self.settings.auto_reload;
+                let tab = self.tab_mut();
+                if !auto_reload {
+                    // The user is only told once until the files are compared again
+                    if !tab.changed_on_disk {
+                        tab.changed_on_disk = true;
+                        ApplicationContext::notify(
+                            self,
+                            Severity::Info,
+                            &format!("{} was changed on disk. Compare again to reload it.", path),
+                        );
+                    }
+                    return Command::none();
+                }
+
+                // Files that are written in multiple steps are only reloaded once
+                tab.reload_at = Some(Instant::now() + REDIFF_DELAY);
+            }
+            Message::NotificationDismissed(id) => self.notifications.dismiss(id),
+            Message::IgnoreRuleInputChanged(d) => self.ignore_rule = d,
+            Message::IgnoreRuleSubmitted => {
+                if self.ignore_rule.is_empty() {
+                    return Command::none();
+                }
+
+                let pattern = std::mem::take(&mut self.ignore_rule);
+                if let Err(e) = IgnoreRules::new(vec![pattern.clone()]).apply(vec![]) {
+                    self.ignore_rule = pattern;
+                    ApplicationContext::display_error(self, &e);
+                    return Command::none();
+                }
+
+                let tab = &mut self.tabs[self.active_tab];
+                self.settings.ignore_rules.patterns.push(pattern.clone());
+                tab.ignore_rules.patterns.push(pattern);
+                tab.btn_ignore_rules.push(button::State::default());
+                self.save_settings();
+            }
+            Message::IgnoreRuleRemoved(index) => {
+                let tab = &mut self.tabs[self.active_tab];
+                if index < tab.ignore_rules.patterns.len() {
+                    let pattern = tab.ignore_rules.patterns.remove(index);
+                    tab.btn_ignore_rules.remove(index);
+                    self.settings
+                        .ignore_rules
+                        .patterns
+                        .retain(|p| *p != pattern);
+                    self.save_settings();
+                }
+            }
+            Message::RecentSelected(d) => {
+                let tab = self.tab_mut();
+                tab.first_revisions = ApplicationContext::load_revisions(&d.first_file);
+                tab.second_revisions = ApplicationContext::load_revisions(&d.second_file);
+                tab.first_revision = Revision::WorkingTree;
+                tab.second_revision = Revision::WorkingTree;
+                tab.first_file = d.first_file;
+                tab.second_file = d.second_file;
+                return self.update(Message::ComparePressed);
+            }
+            Message::SystemThemeChanged(d) => style::set_prefers_dark(d),
+            Message::FileDropped(path) => {
+                if self.merge.is_some() || self.tab().input_mode == InputMode::Text {
+                    return Command::none();
+                }
+
+                let path = match path_to_string(Some(path)) {
+                    Ok(Some(d)) => d,
+                    Ok(None) => return Command::none(),
+                    Err(e) => {
+                        ApplicationContext::display_error(self, &e);
+                        return Command::none();
+                    }
+                };
+
+                // Files that are dropped together arrive one by one in quick succession
+                let tab = &mut self.tabs[self.active_tab];
+                match self.dropped_file.take() {
+                    Some((previous, time)) if time.elapsed() < DROP_INTERVAL => {
+                        tab.set_first_file(previous);
+                        tab.set_second_file(path);
+                        return self.update(Message::ComparePressed);
+                    }
+                    _ => {
+                        self.dropped_file = Some((path.clone(), Instant::now()));
+                        if tab.first_file.is_empty() || !tab.second_file.is_empty() {
+                            tab.set_first_file(path);
+                            tab.second_file = String::new();
+                            tab.second_revisions = vec![];
+                            tab.second_revision = Revision::WorkingTree;
+                        } else {
+                            tab.set_second_file(path);
+                        }
+                    }
+                }
+            }
+            Message::FolderEntrySelected(index) => {
+                let tab = self.tab();
+                let path = match tab.folder_entries.get(index) {
+                    Some(d) => String::from(d.path()),
+                    None => return Command::none(),
+                };
 
-    fn new() -> Self {
-        ApplicationContext::default()
-    }
+                // The entry is compared in a tab of its own, so that the folders stay listed
+                let first_file = Path::new(&tab.first_file).join(&path);
+                let second_file = Path::new(&tab.second_file).join(&path);
+                let compare_mode = tab.compare_mode;
+                let ignore_rules = tab.ignore_rules.clone();
 
-    fn title(&self) -> String {
-        String::from("text-diff")
-    }
+                self.new_tab();
+                let tab = self.tab_mut();
+                tab.compare_mode = compare_mode;
+                tab.btn_ignore_rules = ignore_rules
+                    .patterns
+                    .iter()
+                    .map(|_| button::State::default())
+                    .collect();
+                tab.ignore_rules = ignore_rules;
+                tab.set_first_file(first_file.display().to_string());
+                tab.set_second_file(second_file.display().to_string());
+                self.save_settings();
+                return self.update(Message::ComparePressed);
+            }
+            Message::InputModeChanged(d) => {
+                let tab = self.tab_mut();
+                tab.input_mode = d;
+                tab.has_compared = false;
+                tab.differences = vec![];
+                tab.statistics = None;
+                tab.folder_entries = vec![];
+                tab.btn_folder_entries = vec![];
+                tab.is_folder_comparison = false;
+            }
+            Message::TextLineChanged(side, index, d) => {
+                let tab = self.tab_mut();
+                match side {
+                    Side::First => tab.first_text.change_line(index, d),
+                    Side::Second => tab.second_text.change_line(index, d),
+                };
+                tab.schedule_rediff();
+            }
+            Message::TextLineSubmitted(side, index) => {
+                let tab = self.tab_mut();
+                match side {
+                    Side::First => tab.first_text.insert_line_after(index),
+                    Side::Second => tab.second_text.insert_line_after(index),
+                };
+                tab.schedule_rediff();
+            }
+            Message::TextScrolled(side, d) => match side {
+                Side::First => self.tab_mut().first_text.scrolled(d),
+                Side::Second => self.tab_mut().second_text.scrolled(d),
+            },
+            Message::SaveTextPressed(side) => self.save_text(side),
+            Message::TextUndoPressed(side) => {
+                let tab = self.tab_mut();
+                let changed = match side {
+                    Side::First => tab.first_text.undo(),
+                    Side::Second => tab.second_text.undo(),
+                };
+                if changed {
+                    tab.schedule_rediff();
+                }
+            }
+            Message::TextRedoPressed(side) => {
+                let tab = self.tab_mut();
+                let changed = match side {
+                    Side::First => tab.first_text.redo(),
+                    Side::Second => tab.second_text.redo(),
+                };
+                if changed {
+                    tab.schedule_rediff();
+                }
+            }
+            Message::ViewModeChanged(d) => {
+                let tab = &mut self.tabs[self.active_tab];
+                tab.view_mode = d;
+                tab.current_change = None;
+                self.settings.view_mode = d;
+                self.save_settings();
+            }
+            Message::ContextLinesChanged(d) => {
+                let tab = &mut self.tabs[self.active_tab];
+                tab.side_by_side.context = d;
+                tab.side_by_side
+                    .layout(tab.first_text.lines().len(), tab.second_text.lines().len());
+                self.settings.context_lines = d;
+                self.save_settings();
+            }
+            Message::ListScrolled(d) => self.tab_mut().differences_list.scrolled(d),
+            Message::TabSelected(d) => {
+                self.pending_action = None;
+                if self.select_tab(d) {
+                    return self.update(Message::ComparePressed);
+                }
+                self.save_settings();
+            }
+            Message::TabClosed(d) => {
+                if !self.confirm_discard(d, Message::TabClosed(d)) {
+                    return Command::none();
+                }
 
-    fn update(&mut self, message: Self::Message) {
-        match message {
-            Message::FirstFileInputChanged(d) => self.first_file = d,
-            Message::SecondFileInputChanged(d) => self.second_file = d,
-            Message::SelectFirstFilePressed => {
-                let path = match ApplicationContext::open_file_dialog() {
+                self.pending_action = None;
+                if self.close_tab(d) {
+                    return self.update(Message::ComparePressed);
+                }
+                self.save_settings();
+            }
+            Message::NewTabPressed => {
+                self.pending_action = None;
+                self.new_tab();
+                self.save_settings();
+            }
+            Message::OpenSessionPressed => {
+                let path = match ApplicationContext::open_file_dialog(&[(
+                    "Session file",
+                    &[session::EXTENSION],
+                )]) {
                     Ok(res) => match res {
                         Some(d) => d,
-                        None => return,
+                        None => return Command::none(),
                     },
                     Err(e) => {
-                        ApplicationContext::display_alert(
-                            &self,
-                            "text-diff",
-                            &format!("Error while selecting file!\n{:?}", e),
-                            MessageType::Error,
-                        );
-                        return;
+                        ApplicationContext::display_error(self, &e);
+                        return Command::none();
+                    }
+                };
+
+                let session = match Session::load(&path) {
+                    Ok(d) => d,
+                    Err(e) => {
+                        ApplicationContext::display_error(self, &e);
+                        return Command::none();
                     }
                 };
 
-                self.first_file = path;
+                // The selected tab is only reused when nothing was entered in it yet
+                let tab = self.tab();
+                if tab.has_compared
+                    || !tab.first_file.is_empty()
+                    || !tab.second_file.is_empty()
+                    || !tab.first_text.is_empty()
+                    || !tab.second_text.is_empty()
+                {
+                    self.new_tab();
+                }
+
+                let command = self.open_session(session);
+                self.save_settings();
+                return command;
             }
-            Message::SelectSecondFilePressed => {
-                let path = match ApplicationContext::open_file_dialog() {
+            Message::SaveSessionPressed => {
+                let path = match ApplicationContext::save_file_dialog(&[(
+                    "Session file",
+                    &[session::EXTENSION],
+                )]) {
                     Ok(res) => match res {
                         Some(d) => d,
-                        None => return,
+                        None => return Command::none(),
                     },
                     Err(e) => {
-                        ApplicationContext::display_alert(
-                            &self,
-                            "text-diff",
-                            &format!("Error while selecting file!\n{:?}", e),
-                            MessageType::Error,
-                        );
-                        return;
+                        ApplicationContext::display_error(self, &e);
+                        return Command::none();
                     }
                 };
 
-                self.second_file = path;
+                let path = if Path::new(&path).extension().is_none() {
+                    format!("{}.{}", path, session::EXTENSION)
+                } else {
+                    path
+                };
+
+                match self.session().save(&path) {
+                    Ok(_) => ApplicationContext::notify(
+                        self,
+                        Severity::Info,
+                        &format!("The session was saved to {}", path),
+                    ),
+                    Err(e) => ApplicationContext::display_error(self, &e),
+                }
             }
-            Message::ComparePressed => {
-                if self.first_file.is_empty() || self.second_file.is_empty() {
-                    ApplicationContext::display_alert(
-                        &self,
-                        "text-diff",
-                        "Please select two files first!",
-                        MessageType::Warning,
+            Message::LineNumberPressed(side, line) => {
+                self.tab_mut().side_by_side.select_line(side, line)
+            }
+            Message::CopyLocationPressed => {
+                if let Some(d) = self.tab().side_by_side.selection {
+                    let location = self.tab().location(d);
+                    self.notify(
+                        Severity::Info,
+                        &format!("Copied {} to the clipboard", location),
                     );
-                    return;
+                    return clipboard::write(location);
+                }
+            }
+            Message::OverviewPressed(line) => self.tab_mut().go_to_overview(line),
+            Message::SideBySideScrolled(d) => self.tab_mut().side_by_side.list.scrolled(d),
+            Message::FoldExpanded(region, all) => {
+                let tab = self.tab_mut();
+                tab.side_by_side.expand(region, all);
+                tab.side_by_side
+                    .layout(tab.first_text.lines().len(), tab.second_text.lines().len());
+            }
+            Message::HunkCopiedToSecond(index) => {
+                let tab = self.tab_mut();
+                if let Some(hunk) = tab.side_by_side.hunk(index) {
+                    let lines = tab.first_text.lines();
+                    let lines = lines
+                        .get(hunk.old_index..hunk.old_index + hunk.old_len)
+                        .unwrap_or_default()
+                        .to_vec();
+                    tab.second_text.replace_range(
+                        hunk.new_index,
+                        hunk.new_index + hunk.new_len,
+                        lines,
+                    );
+                    self.rediff(self.active_tab);
+                }
+            }
+            Message::HunkCopiedToFirst(index) => {
+                let tab = self.tab_mut();
+                if let Some(hunk) = tab.side_by_side.hunk(index) {
+                    let lines = tab.second_text.lines();
+                    let lines = lines
+                        .get(hunk.new_index..hunk.new_index + hunk.new_len)
+                        .unwrap_or_default()
+                        .to_vec();
+                    tab.first_text.replace_range(
+                        hunk.old_index,
+                        hunk.old_index + hunk.old_len,
+                        lines,
+                    );
+                    self.rediff(self.active_tab);
+                }
+            }
+            Message::HunkReverted(index) => {
+                let tab = self.tab_mut();
+                if let Some(hunk) = tab.side_by_side.hunk(index) {
+                    let first = tab
+                        .first_text
+                        .revert_range(hunk.old_index, hunk.old_index + hunk.old_len);
+                    let second = tab
+                        .second_text
+                        .revert_range(hunk.new_index, hunk.new_index + hunk.new_len);
+                    if first || second {
+                        self.rediff(self.active_tab);
+                    } else {
+                        ApplicationContext::notify(
+                            self,
+                            Severity::Info,
+                            "The change is not an edit that can be reverted",
+                        );
+                    }
+                }
+            }
+            Message::SideFilterChanged(d) => {
+                let tab = self.tab_mut();
+                tab.filter.side = d;
+                tab.apply_filter();
+            }
+            Message::FilterPatternChanged(d) => {
+                let tab = self.tab_mut();
+                tab.filter.pattern = d;
+                tab.apply_filter();
+            }
+            Message::HideWhitespaceToggled(d) => {
+                let tab = self.tab_mut();
+                tab.filter.hide_whitespace = d;
+                tab.apply_filter();
+            }
+            Message::ExportFilteredToggled(d) => self.tab_mut().export_filtered = d,
+            Message::SearchChanged(d) => self.tab_mut().search = d,
+            Message::SearchSubmitted | Message::NextMatchPressed => {
+                self.tab_mut().go_to_match(true)
+            }
+            Message::PreviousMatchPressed => self.tab_mut().go_to_match(false),
+            Message::NextChangePressed => self.tab_mut().go_to_change(true),
+            Message::PreviousChangePressed => self.tab_mut().go_to_change(false),
+            Message::ShortcutPressed(shortcut) => {
+                if self.merge.is_some() {
+                    return Command::none();
                 }
 
-                let file_reader = FileReader::new();
+                match shortcut {
+                    Shortcut::Compare => return self.update(Message::ComparePressed),
+                    Shortcut::OpenFiles if self.tab().input_mode == InputMode::Files => {
+                        // The second file is not asked for when the first dialog was cancelled
+                        if self.select_file(Side::First) {
+                            self.select_file(Side::Second);
+                        }
+                    }
+                    Shortcut::OpenFiles => {}
+                    Shortcut::Export => {
+                        let tab = self.tab();
+                        if tab.has_compared
+                            && !tab.is_folder_comparison
+                            && !tab.differences.is_empty()
+                        {
+                            return self.update(Message::ExportPressed);
+                        }
+                    }
+                    Shortcut::Clear => return self.update(Message::ClearComparePressed),
+                    Shortcut::NewTab => return self.update(Message::NewTabPressed),
+                    Shortcut::ToggleTheme => {
+                        let themes = style::Theme::all();
+                        let index = themes.iter().position(|t| *t == self.theme).unwrap_or(0);
+                        let theme = themes[(index + 1) % themes.len()];
+                        return self.update(Message::ThemeChanged(theme));
+                    }
+                    Shortcut::NextChange => self.tab_mut().go_to_change(true),
+                    Shortcut::PreviousChange => self.tab_mut().go_to_change(false),
+                }
+            }
+            Message::TimerTicked(now) => {
+                for index in 0..self.tabs.len() {
+                    let tab = &mut self.tabs[index];
+                    if tab.rediff_at.is_some_and(|d| d <= now) {
+                        tab.rediff_at = None;
+                        if tab.has_compared {
+                            self.rediff(index);
+                        }
+                    }
 
-                let lines_first_file = file_reader.read_lines(&self.first_file);
-                let lines_second_file = file_reader.read_lines(&self.second_file);
+                    let tab = &mut self.tabs[index];
+                    if tab.reload_at.is_some_and(|d| d <= now) {
+                        tab.reload_at = None;
+                        self.reload_files(index);
+                    }
+                }
+            }
+            Message::WindowResized(width, height) => self.settings.window_size = (width, height),
+            Message::CloseRequested => {
+                let dirty_tab = self.tabs.iter().position(Comparison::is_dirty);
+                if let Some(index) = dirty_tab {
+                    if !self.confirm_discard(index, Message::CloseRequested) {
+                        return Command::none();
+                    }
+                }
 
-                let lines_first_file = match lines_first_file {
-                    Ok(d) => d,
-                    Err(e) => {
-                        ApplicationContext::display_alert(
-                            &self,
-                            "text-diff",
-                            &format!("Error while reading file {}!\n{}", &self.first_file, e),
-                            MessageType::Error,
-                        );
-                        return;
+                self.save_settings();
+                self.should_exit = true;
+            }
+            Message::SaveEditsPressed => {
+                for side in [Side::First, Side::Second] {
+                    let tab = self.tab();
+                    let is_dirty = match side {
+                        Side::First => tab.first_text.is_dirty(),
+                        Side::Second => tab.second_text.is_dirty(),
+                    };
+                    if is_dirty {
+                        self.save_text(side);
                     }
-                };
+                }
 
-                let lines_second_file = match lines_second_file {
-                    Ok(d) => d,
-                    Err(e) => {
-                        ApplicationContext::display_alert(
-                            &self,
-                            "text-diff",
-                            &format!("Error while reading file {}!\n{}", &self.second_file, e),
-                            MessageType::Error,
-                        );
-                        return;
+                // The action is only continued once every edit was saved
+                if !self.tab().is_dirty() {
+                    if let Some(action) = self.pending_action.take() {
+                        return self.update(action);
                     }
-                };
+                }
+            }
+            Message::DiscardEditsPressed => {
+                if let Some(action) = self.pending_action.take() {
+                    self.discard_confirmed = true;
+                    let command = self.update(action);
+                    self.discard_confirmed = false;
+                    return command;
+                }
+            }
+            Message::CancelActionPressed => self.pending_action = None,
+        };
 
-                let vector_comparer: VectorComparer<String> =
-                    IVectorComparer::<String>::new(lines_first_file, lines_second_file);
+        Command::none()
+    }
 
-                self.differences = vector_comparer.get_differences();
-                self.has_compared = true;
+    fn subscription(&self) -> Subscription<Message> {
+        let events = iced_native::subscription::events_with(|event, status| match event {
+            Event::Window(window::Event::Resized { width, height }) => {
+                Some(Message::WindowResized(width, height))
             }
-            Message::ThemeChanged(d) => self.theme = d,
-            Message::ClearComparePressed => {
-                self.first_file = String::new();
-                self.second_file = String::new();
-                self.has_compared = false;
-                self.differences = vec![];
+            Event::Window(window::Event::CloseRequested) => Some(Message::CloseRequested),
+            Event::Window(window::Event::FileDropped(path)) => Some(Message::FileDropped(path)),
+            // Keys that a widget handled, such as typing in a text input, are not shortcuts
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if status == event::Status::Ignored => {
+                Shortcut::from_key(key_code, modifiers).map(Message::ShortcutPressed)
             }
-            Message::ExportPressed => {
-                let path = FileDialog::new()
-                    .add_filter("Text file", &["txt"])
-                    .add_filter("Csv file", &["csv"])
-                    .add_filter("Json file", &["json"])
-                    .show_save_single_file()
-                    .unwrap();
-
-                let path = match path {
-                    Some(path) => path,
-                    None => return,
-                };
+            _ => None,
+        });
 
-                let path = path.into_os_string().into_string().unwrap();
+        let mut subscriptions = vec![events];
+        if self.theme == style::Theme::System {
+            subscriptions.push(system_theme::subscription().map(Message::SystemThemeChanged));
+        }
 
-                let extension = match Path::new(&path).extension().and_then(OsStr::to_str) {
-                    Some(x) => x,
-                    None => return,
-                };
+        // The timer only runs while an update of a tab is delayed
+        if self
+            .tabs
+            .iter()
+            .any(|t| t.rediff_at.is_some() || t.reload_at.is_some())
+        {
+            subscriptions.push(timer::every(TIMER_INTERVAL).map(Message::TimerTicked));
+        }
 
-                let extension = match extension.to_lowercase().as_str() {
-                    "txt" => ExportType::Text,
-                    "csv" => ExportType::Csv,
-                    "json" => ExportType::Json,
-                    _ => ExportType::default(),
-                };
+        let watched_files = self.watched_files();
+        if !watched_files.is_empty() {
+            subscriptions.push(file_watcher::subscription(watched_files).map(Message::FileChanged));
+        }
 
-                let vec_exporter: VectorExporter<String> =
-                    IVectorExporter::<String>::new(self.differences.clone(), extension, &path);
+        Subscription::batch(subscriptions)
+    }
 
-                match vec_exporter.export() {
-                    Ok(_) => return,
-                    Err(e) => match e {
-                        crate::vector_exporter::ExportError::IoError(e) => {
-                            ApplicationContext::display_alert(
-                                &self,
-                                "text-diff",
-                                &format!("Error while writing to file {}!\n{}", &path, e),
-                                MessageType::Error,
-                            );
-                        }
-                        crate::vector_exporter::ExportError::JsonError(e) => {
-                            ApplicationContext::display_alert(
-                                &self,
-                                "text-diff",
-                                &format!("Error while creating JSON for file {}!\n{}", &path, e),
-                                MessageType::Error,
-                            );
-                        }
-                    },
-                };
-            }
-        };
+    fn should_exit(&self) -> bool {
+        self.should_exit
     }
 
     fn view(&mut self) -> Element<'_, Self::Message> {
+        if self.merge.is_some() {
+            return self.view_merge();
+        }
+
+        // The changes are counted before the fields of the context are borrowed by the widgets
+        let tab_titles = self.tab_titles();
+        let tab = &mut self.tabs[self.active_tab];
+        let visible_changes = tab.visible_changes();
+        let current_index = tab
+            .current_change
+            .and_then(|d| visible_changes.get(d).copied());
+        let match_count = tab.search_matches().len();
+        let (overview_marks, overview_len) = tab.overview_marks(self.theme);
+        let selection = tab.side_by_side.selection;
+        let is_empty = match tab.view_mode {
+            ViewMode::List => tab.differences.is_empty(),
+            ViewMode::SideBySide => tab.side_by_side.hunks.is_empty(),
+        };
+
         let title = Text::new("text-diff")
             .width(Length::Fill)
-            .size(80)
+            .size(self.theme.font_sizes().title)
             .horizontal_alignment(alignment::Horizontal::Center);
 
-        let choose_theme = style::Theme::ALL.iter().fold(
+        let choose_theme = style::Theme::all().into_iter().fold(
             Row::new().width(Length::Fill).spacing(10),
             |row, theme| {
                 row.push(
                     Radio::new(
-                        *theme,
-                        format!("{:?}", theme),
+                        theme,
+                        theme.to_string(),
                         Some(self.theme),
                         Message::ThemeChanged,
                     )
@@ -281,11 +3921,12 @@ impl Sandbox for ApplicationContext {
         let first_file_input = TextInput::new(
             &mut self.first_file_input,
             "/path/to/first/file.txt",
-            &self.first_file,
+            &tab.first_file,
             Message::FirstFileInputChanged,
         )
+        .on_submit(Message::FirstFileInputSubmitted)
         .padding(10)
-        .size(20)
+        .size(self.theme.font_sizes().text)
         .style(self.theme);
 
         let btn_select_first_file = Button::new(
@@ -300,11 +3941,12 @@ impl Sandbox for ApplicationContext {
         let second_file_input = TextInput::new(
             &mut self.second_file_input,
             "/path/to/second/file.txt",
-            &self.second_file,
+            &tab.second_file,
             Message::SecondFileInputChanged,
         )
+        .on_submit(Message::SecondFileInputSubmitted)
         .padding(10)
-        .size(20)
+        .size(self.theme.font_sizes().text)
         .style(self.theme);
 
         let btn_select_second_file = Button::new(
@@ -316,6 +3958,38 @@ impl Sandbox for ApplicationContext {
         .on_press(Message::SelectSecondFilePressed)
         .style(self.theme);
 
+        let mut first_file_row = Row::new().spacing(10).push(first_file_input);
+        if !tab.first_revisions.is_empty() {
+            first_file_row = first_file_row.push(
+                PickList::new(
+                    &mut self.first_revision_picker,
+                    &tab.first_revisions[..],
+                    Some(tab.first_revision.clone()),
+                    Message::FirstRevisionChanged,
+                )
+                .padding(10)
+                .width(Length::Units(180))
+                .style(self.theme),
+            );
+        }
+        first_file_row = first_file_row.push(btn_select_first_file);
+
+        let mut second_file_row = Row::new().spacing(10).push(second_file_input);
+        if !tab.second_revisions.is_empty() {
+            second_file_row = second_file_row.push(
+                PickList::new(
+                    &mut self.second_revision_picker,
+                    &tab.second_revisions[..],
+                    Some(tab.second_revision.clone()),
+                    Message::SecondRevisionChanged,
+                )
+                .padding(10)
+                .width(Length::Units(180))
+                .style(self.theme),
+            );
+        }
+        second_file_row = second_file_row.push(btn_select_second_file);
+
         let btn_compare = Button::new(
             &mut self.btn_compare,
             Text::new("Compare").horizontal_alignment(alignment::Horizontal::Center),
@@ -325,9 +3999,69 @@ impl Sandbox for ApplicationContext {
         .on_press(Message::ComparePressed)
         .style(self.theme);
 
+        let choose_compare_mode = CompareMode::ALL.iter().fold(
+            Row::new().width(Length::Fill).spacing(10),
+            |row, compare_mode| {
+                row.push(
+                    Radio::new(
+                        *compare_mode,
+                        compare_mode.to_string(),
+                        Some(tab.compare_mode),
+                        Message::CompareModeChanged,
+                    )
+                    .style(self.theme),
+                )
+            },
+        );
+
+        let ignore_rule_input = TextInput::new(
+            &mut self.ignore_rule_input,
+            "Ignore lines matching a regular expression",
+            &self.ignore_rule,
+            Message::IgnoreRuleInputChanged,
+        )
+        .on_submit(Message::IgnoreRuleSubmitted)
+        .padding(10)
+        .size(self.theme.font_sizes().text)
+        .style(self.theme);
+
+        let theme = self.theme;
+        let ignore_rules = tab
+            .ignore_rules
+            .patterns
+            .iter()
+            .zip(tab.btn_ignore_rules.iter_mut())
+            .enumerate()
+            .fold(
+                Row::new().spacing(10).push(ignore_rule_input),
+                |row, (index, (pattern, state))| {
+                    row.push(
+                        Button::new(state, Text::new(format!("✕ {}", pattern)))
+                            .padding(10)
+                            .on_press(Message::IgnoreRuleRemoved(index))
+                            .style(theme),
+                    )
+                },
+            );
+
+        let choose_input_mode = InputMode::ALL.iter().fold(
+            Row::new().width(Length::Fill).spacing(10),
+            |row, input_mode| {
+                row.push(
+                    Radio::new(
+                        *input_mode,
+                        input_mode.to_string(),
+                        Some(tab.input_mode),
+                        Message::InputModeChanged,
+                    )
+                    .style(theme),
+                )
+            },
+        );
+
         let mut compare_row = Row::new().spacing(10);
 
-        if self.has_compared {
+        if tab.has_compared {
             let btn_clean_compare = Button::new(
                 &mut self.btn_clean_compare,
                 Text::new("Clear").horizontal_alignment(alignment::Horizontal::Center),
@@ -346,59 +4080,394 @@ impl Sandbox for ApplicationContext {
             );
         }
 
+        let mut compare_buttons = Row::new().spacing(10);
+        if !self.settings.recent.is_empty() {
+            compare_buttons = compare_buttons.push(
+                PickList::new(
+                    &mut self.recent_picker,
+                    &self.settings.recent[..],
+                    None,
+                    Message::RecentSelected,
+                )
+                .placeholder("Recent")
+                .padding(10)
+                .width(Length::Units(180))
+                .style(self.theme),
+            );
+        }
+
         compare_row = compare_row.push(
             Column::new()
                 .width(Length::Fill)
                 .align_items(Alignment::End)
                 .spacing(20)
-                .push(btn_compare),
+                .push(compare_buttons.push(btn_compare)),
         );
 
+        self.btn_tabs
+            .resize_with(tab_titles.len(), Default::default);
+        let active_tab = self.active_tab;
+        let tabs = self
+            .btn_tabs
+            .iter_mut()
+            .zip(tab_titles)
+            .enumerate()
+            .fold(
+                Row::new().spacing(5).align_items(Alignment::Center),
+                |row, (index, ([btn_select, btn_close], title))| {
+                    row.push(
+                        Button::new(btn_select, Text::new(title).size(16))
+                            .padding(5)
+                            .on_press(Message::TabSelected(index))
+                            .style(style::Tab(theme, index == active_tab)),
+                    )
+                    .push(
+                        Button::new(btn_close, Text::new("×").size(16))
+                            .padding(5)
+                            .on_press(Message::TabClosed(index))
+                            .style(theme),
+                    )
+                },
+            )
+            .push(
+                Button::new(&mut self.btn_new_tab, Text::new("+").size(16))
+                    .padding(5)
+                    .on_press(Message::NewTabPressed)
+                    .style(theme),
+            )
+            .push(Space::with_width(Length::Fill))
+            .push(
+                Button::new(
+                    &mut self.btn_open_session,
+                    Text::new("Open session").size(16),
+                )
+                .padding(5)
+                .on_press(Message::OpenSessionPressed)
+                .style(theme),
+            )
+            .push(
+                Button::new(
+                    &mut self.btn_save_session,
+                    Text::new("Save session").size(16),
+                )
+                .padding(5)
+                .on_press(Message::SaveSessionPressed)
+                .style(theme),
+            );
+
         let mut content = Column::new()
             .spacing(15)
             .padding(20)
             .max_width(800)
             .push(title)
             .push(Rule::horizontal(20).style(self.theme))
-            .push(
-                Row::new()
-                    .spacing(10)
-                    .push(first_file_input)
-                    .push(btn_select_first_file),
-            )
-            .push(
-                Row::new()
-                    .spacing(10)
-                    .push(second_file_input)
-                    .push(btn_select_second_file),
-            )
+            .push(self.notifications.view(self.theme));
+
+        if self.pending_action.is_some() {
+            content = content.push(unsaved_edits_row(
+                [
+                    &mut self.btn_save_edits,
+                    &mut self.btn_discard_edits,
+                    &mut self.btn_cancel_action,
+                ],
+                theme,
+            ));
+        }
+
+        content = content.push(tabs).push(choose_input_mode);
+
+        let side_by_side = if tab.view_mode == ViewMode::SideBySide && tab.has_compared {
+            Some(tab.side_by_side.view(
+                tab.first_text.lines(),
+                tab.second_text.lines(),
+                tab.language,
+                current_index,
+                &tab.search,
+                theme,
+            ))
+        } else {
+            None
+        };
+
+        let (first_title, second_title) = match tab.input_mode {
+            InputMode::Files => (tab.first_file.as_str(), tab.second_file.as_str()),
+            InputMode::Text => ("First text", "Second text"),
+        };
+        let panes = Row::new()
+            .spacing(10)
+            .push(tab.first_text.view(Side::First, first_title, theme))
+            .push(tab.second_text.view(Side::Second, second_title, theme));
+
+        content = match tab.input_mode {
+            InputMode::Files if tab.has_compared && !tab.is_folder_comparison => content
+                .push(first_file_row)
+                .push(second_file_row)
+                .push(panes),
+            InputMode::Files => content.push(first_file_row).push(second_file_row),
+            InputMode::Text => content.push(panes),
+        };
+
+        content = content
+            .push(choose_compare_mode)
+            .push(ignore_rules)
             .push(compare_row);
 
-        if self.has_compared {
+        if tab.has_compared && tab.is_folder_comparison {
             let mut diff_text = Text::new("Differences:");
-            if self.differences.is_empty() {
+            if tab.folder_entries.is_empty() {
                 diff_text = Text::new("No differences detected!")
             }
 
-            let diff_column = self
-                .differences
+            let theme = self.theme;
+            let entry_column = tab
+                .folder_entries
                 .iter()
-                .fold(Column::new().spacing(10), |column, theme| {
-                    column.push(Text::new(format!("- {}", theme)))
-                });
+                .zip(tab.btn_folder_entries.iter_mut())
+                .enumerate()
+                .fold(
+                    Column::new().spacing(10),
+                    |column, (index, (entry, state))| match entry {
+                        FolderEntry::Different(_) => column.push(
+                            Button::new(state, Text::new(entry.to_string()))
+                                .padding(5)
+                                .on_press(Message::FolderEntrySelected(index))
+                                .style(theme),
+                        ),
+                        _ => column.push(Text::new(entry.to_string())),
+                    },
+                );
 
-            let scroll_container = Column::new().width(Length::Fill).push(diff_column);
             let scroll = Scrollable::new(&mut self.scrollable)
-                .push(Container::new(scroll_container).width(Length::Fill))
+                .push(Container::new(entry_column).width(Length::Fill))
                 .max_height(150)
                 .style(self.theme);
 
             content = content
                 .push(Rule::horizontal(20).style(self.theme))
-                .push(diff_text.size(30))
-                .push(scroll);
+                .push(diff_text.size(self.theme.font_sizes().heading))
+                .push(scroll)
+                .push(Rule::horizontal(20).style(self.theme));
+        } else if tab.has_compared {
+            let mut diff_text = Text::new("Differences:");
+            if is_empty {
+                diff_text = Text::new("No differences detected!")
+            }
+
+            let choose_view_mode =
+                ViewMode::ALL
+                    .iter()
+                    .fold(Row::new().spacing(10), |row, view_mode| {
+                        row.push(
+                            Radio::new(
+                                *view_mode,
+                                view_mode.to_string(),
+                                Some(tab.view_mode),
+                                Message::ViewModeChanged,
+                            )
+                            .style(theme),
+                        )
+                    });
+
+            let mut view_options = Row::new()
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .push(choose_view_mode);
+            if tab.view_mode == ViewMode::SideBySide {
+                view_options = view_options.push(Text::new("Context lines:")).push(
+                    PickList::new(
+                        &mut self.context_picker,
+                        &CONTEXT_SIZES[..],
+                        Some(self.settings.context_lines),
+                        Message::ContextLinesChanged,
+                    )
+                    .padding(5)
+                    .style(theme),
+                );
+
+                if selection.is_some() {
+                    view_options = view_options.push(
+                        Button::new(&mut self.btn_copy_location, Text::new("Copy location"))
+                            .padding(5)
+                            .on_press(Message::CopyLocationPressed)
+                            .style(theme),
+                    );
+                }
+            }
+
+            let scroll = match side_by_side {
+                Some(d) => d,
+                None => {
+                    let lexer = Lexer::new(tab.language);
+                    let differences = &tab.differences;
+                    let search = tab.search.as_str();
+                    let visible_differences = &tab.visible_differences;
+                    tab.differences_list.view(
+                        visible_differences.len(),
+                        ROW_HEIGHT,
+                        LIST_HEIGHT,
+                        theme,
+                        Message::ListScrolled,
+                        move |position| {
+                            let index = visible_differences[position];
+                            let difference = &differences[index];
+                            let prefix = if current_index == Some(index) {
+                                "▶ "
+                            } else {
+                                "- "
+                            };
+                            let (first, second) = match difference.origin {
+                                Origin::First => (Some(difference.line), None),
+                                Origin::Second => (None, Some(difference.line)),
+                            };
+                            Row::new()
+                                .spacing(10)
+                                .push(line_number(first, theme))
+                                .push(line_number(second, theme))
+                                .push(searched_line(
+                                    &lexer,
+                                    theme,
+                                    (prefix, theme.diff_colors().changed.0),
+                                    &difference.text,
+                                    search,
+                                ))
+                                .into()
+                        },
+                    )
+                }
+            };
+
+            let side_filter = PickList::new(
+                &mut self.side_filter_picker,
+                &SideFilter::ALL[..],
+                Some(tab.filter.side),
+                Message::SideFilterChanged,
+            )
+            .padding(10)
+            .width(Length::Units(180))
+            .style(theme);
+
+            let filter_pattern = TextInput::new(
+                &mut self.filter_pattern_input,
+                "Only show changes matching a regular expression",
+                &tab.filter.pattern,
+                Message::FilterPatternChanged,
+            )
+            .padding(10)
+            .size(self.theme.font_sizes().text)
+            .style(theme);
+
+            let hide_whitespace = Checkbox::new(
+                tab.filter.hide_whitespace,
+                "Hide whitespace changes",
+                Message::HideWhitespaceToggled,
+            )
+            .style(theme);
+
+            let filter_row = Row::new()
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .push(side_filter)
+                .push(filter_pattern)
+                .push(hide_whitespace);
+
+            let search_input = TextInput::new(
+                &mut self.search_input,
+                "Search",
+                &tab.search,
+                Message::SearchChanged,
+            )
+            .on_submit(Message::SearchSubmitted)
+            .padding(10)
+            .size(self.theme.font_sizes().text)
+            .style(theme);
+
+            let mut btn_previous_match = Button::new(&mut self.btn_previous_match, Text::new("↑"))
+                .padding(10)
+                .style(theme);
+            let mut btn_next_match = Button::new(&mut self.btn_next_match, Text::new("↓"))
+                .padding(10)
+                .style(theme);
+            if match_count > 0 {
+                btn_previous_match = btn_previous_match.on_press(Message::PreviousMatchPressed);
+                btn_next_match = btn_next_match.on_press(Message::NextMatchPressed);
+            }
+
+            let search_row = Row::new()
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .push(search_input)
+                .push(Text::new(format!("{} matches", match_count)))
+                .push(btn_previous_match)
+                .push(btn_next_match);
+
+            let change_count = visible_changes.len();
+            let position = match tab.current_change {
+                Some(d) => format!("Change {} of {}", d + 1, change_count),
+                None => format!("{} changes", change_count),
+            };
+
+            let mut btn_previous_change =
+                Button::new(&mut self.btn_previous_change, Text::new("↑ Previous"))
+                    .padding(5)
+                    .style(theme);
+            let mut btn_next_change = Button::new(&mut self.btn_next_change, Text::new("↓ Next"))
+                .padding(5)
+                .style(theme);
+            if change_count > 0 {
+                btn_previous_change = btn_previous_change.on_press(Message::PreviousChangePressed);
+                btn_next_change = btn_next_change.on_press(Message::NextChangePressed);
+            }
+
+            content = content
+                .push(Rule::horizontal(20).style(self.theme))
+                .push(
+                    Row::new()
+                        .spacing(20)
+                        .align_items(Alignment::Center)
+                        .push(
+                            diff_text
+                                .size(self.theme.font_sizes().heading)
+                                .width(Length::Fill),
+                        )
+                        .push(view_options),
+                )
+                .push(
+                    Row::new()
+                        .spacing(10)
+                        .align_items(Alignment::Center)
+                        .push(Text::new(position).width(Length::Fill))
+                        .push(btn_previous_change)
+                        .push(btn_next_change),
+                )
+                .push(filter_row)
+                .push(search_row);
+
+            if let Some(d) = &tab.statistics {
+                content = content.push(statistics_panel(d));
+            }
+
+            if let Some(e) = &tab.filter_error {
+                content = content.push(
+                    Text::new(format!("Invalid regular expression: {}", e))
+                        .color(theme.diff_colors().removed.0),
+                );
+            }
 
-            if !self.differences.is_empty() {
+            let height = match tab.view_mode {
+                ViewMode::List => LIST_HEIGHT,
+                ViewMode::SideBySide => SIDE_BY_SIDE_HEIGHT,
+            };
+            content = content.push(
+                Row::new()
+                    .spacing(5)
+                    .push(scroll)
+                    .push(
+                        self.overview_ruler
+                            .view(&overview_marks, overview_len, height, theme),
+                    ),
+            );
+
+            if !tab.differences.is_empty() {
                 let btn_export = Button::new(
                     &mut self.btn_export,
                     Text::new("Export").horizontal_alignment(alignment::Horizontal::Center),
@@ -408,19 +4477,43 @@ impl Sandbox for ApplicationContext {
                 .on_press(Message::ExportPressed)
                 .style(self.theme);
 
+                let export_filtered = Checkbox::new(
+                    tab.export_filtered,
+                    "Only export the filtered differences",
+                    Message::ExportFilteredToggled,
+                )
+                .style(self.theme);
+
                 content = content
                     .push(
-                        Column::new()
-                            .width(Length::Fill)
-                            .align_items(Alignment::End)
+                        Row::new()
                             .spacing(20)
+                            .align_items(Alignment::Center)
+                            .push(Column::new().width(Length::Fill).push(export_filtered))
                             .push(btn_export),
                     )
                     .push(Rule::horizontal(20).style(self.theme));
             }
         }
 
-        content = content.push(choose_theme);
+        let native_dialogs = Checkbox::new(
+            self.native_dialogs,
+            "Use native dialogs",
+            Message::NativeDialogsToggled,
+        )
+        .style(self.theme);
+
+        let auto_reload = Checkbox::new(
+            self.settings.auto_reload,
+            "Reload the files when they change on disk",
+            Message::AutoReloadToggled,
+        )
+        .style(self.theme);
+
+        content = content
+            .push(choose_theme)
+            .push(native_dialogs)
+            .push(auto_reload);
 
         Container::new(content)
             .width(Length::Fill)
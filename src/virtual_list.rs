@@ -0,0 +1,220 @@
+use std::ops::Range;
+
+use iced::{scrollable, Column, Container, Element, Length, Scrollable, Space};
+
+use crate::style;
+use crate::view::Message;
+
+/// The number of rows that are laid out above and below the viewport
+const OVERSCAN: usize = 5;
+
+/// A scrollable list of rows with a fixed height that only lays out the rows that are visible
+///
+/// The rows outside of the viewport are replaced by empty space of the same height, so that the
+/// number of widgets does not depend on the number of rows. The first visible row is kept in view
+/// when the number of rows changes, such as after a re-diff.
+#[derive(Default)]
+pub struct VirtualList {
+    scrollable: scrollable::State,
+    offset: f32,
+    anchor: usize,
+    len: usize,
+    row_height: u16,
+    height: u16,
+}
+
+impl VirtualList {
+    /// Apply a change of the scroll position
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// virtual_list.scrolled(0.5);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The relative scroll position, from `0.0` at the top to `1.0` at the bottom
+    pub fn scrolled(&mut self, offset: f32) {
+        self.offset = offset.clamp(0.0, 1.0);
+        self.anchor =
+            (self.offset * self.scroll_range()) as usize / usize::max(self.row_height as usize, 1);
+    }
+
+    /// Scroll to a row, so that it is the first visible row when possible
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// virtual_list.scroll_to(42);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The index of the row
+    pub fn scroll_to(&mut self, row: usize) {
+        self.anchor = row;
+        self.snap();
+    }
+
+    /// Scroll just enough to show a row, if it is outside of the viewport
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// virtual_list.scroll_into_view(42);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The index of the row
+    pub fn scroll_into_view(&mut self, row: usize) {
+        let visible = usize::max(
+            self.height as usize / usize::max(self.row_height as usize, 1),
+            1,
+        );
+        if row < self.anchor {
+            self.scroll_to(row);
+        } else if row >= self.anchor + visible {
+            self.scroll_to(row + 1 - visible);
+        }
+    }
+
+    /// Get the first visible row
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let row: usize = virtual_list.first_row();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The index of the row at the top of the viewport
+    pub fn first_row(&self) -> usize {
+        self.anchor
+    }
+
+    /// Reset the scroll position to the top of the list
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// virtual_list.reset();
+    /// ```
+    pub fn reset(&mut self) {
+        self.scroll_to(0);
+    }
+
+    /// The height of the content that can be scrolled past, in pixels
+    fn scroll_range(&self) -> f32 {
+        let content = self.len as f32 * self.row_height as f32;
+        f32::max(content - self.height as f32, 0.0)
+    }
+
+    /// Move the scrollable to the first visible row
+    fn snap(&mut self) {
+        let range = self.scroll_range();
+        self.offset = if range > 0.0 {
+            (self.anchor as f32 * self.row_height as f32 / range).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self.scrollable.snap_to(self.offset);
+    }
+
+    /// Get the rows that are visible in the viewport, including a few rows around it
+    fn visible_rows(&self) -> Range<usize> {
+        let row_height = usize::max(self.row_height as usize, 1);
+        let first = (self.offset * self.scroll_range()) as usize / row_height;
+        let count = self.height as usize / row_height + 1;
+
+        let start = usize::min(first.saturating_sub(OVERSCAN), self.len);
+        let end = usize::min(first + count + OVERSCAN, self.len);
+        start..end
+    }
+
+    /// Create the view of the list
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let element: Scrollable<'_, Message> = virtual_list.view(lines.len(), 30, 300, theme, Message::ListScrolled, |i| Text::new(&lines[i]).into())
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of rows
+    /// * `row_height` - The height of every row, in pixels
+    /// * `height` - The maximum height of the list, in pixels
+    /// * `theme` - The `Theme` of the scrollable
+    /// * `on_scroll` - The function that creates the message when the list is scrolled
+    /// * `row` - The function that creates the row with the given index
+    ///
+    /// # Returns
+    ///
+    /// The `Scrollable` that contains the visible rows
+    pub fn view<'a>(
+        &'a mut self,
+        len: usize,
+        row_height: u16,
+        height: u16,
+        theme: style::Theme,
+        on_scroll: fn(f32) -> Message,
+        mut row: impl FnMut(usize) -> Element<'a, Message>,
+    ) -> Scrollable<'a, Message> {
+        if len != self.len || row_height != self.row_height || height != self.height {
+            self.len = len;
+            self.row_height = row_height;
+            self.height = height;
+            self.anchor = usize::min(self.anchor, len.saturating_sub(1));
+            self.snap();
+        }
+
+        let rows = self.visible_rows();
+        let column = spacer(Column::new(), rows.start, row_height);
+        let column = rows.clone().fold(column, |column, index| {
+            column.push(
+                Container::new(row(index))
+                    .width(Length::Fill)
+                    .height(Length::Units(row_height)),
+            )
+        });
+        let column = spacer(column, len - rows.end, row_height);
+
+        Scrollable::new(&mut self.scrollable)
+            .push(column.width(Length::Fill))
+            .width(Length::Fill)
+            .max_height(height as u32)
+            .on_scroll(on_scroll)
+            .style(theme)
+    }
+}
+
+/// Add empty space with the height of a number of rows
+///
+/// The space is split into multiple widgets, because the height of a single widget is limited.
+///
+/// # Arguments
+///
+/// * `column` - The `Column` that the space is added to
+/// * `rows` - The number of rows
+/// * `row_height` - The height of every row, in pixels
+///
+/// # Returns
+///
+/// The `Column` with the added space
+fn spacer(column: Column<'_, Message>, rows: usize, row_height: u16) -> Column<'_, Message> {
+    let per_space = usize::max(u16::MAX as usize / usize::max(row_height as usize, 1), 1);
+    let mut column = column;
+    let mut remaining = rows;
+    while remaining > 0 {
+        let count = usize::min(remaining, per_space);
+        column = column.push(Space::with_height(Length::Units(
+            (count * row_height as usize) as u16,
+        )));
+        remaining -= count;
+    }
+
+    column
+}